@@ -0,0 +1,36 @@
+use std::path::PathBuf;
+
+use anyhow::{bail, Result};
+use clap::Parser;
+use pgtools::compute_fingerprint_from_path;
+
+/// Print a compact fingerprint (pgfp1:<hash>:<S>:<L>:<P>:<bp>) for a GFA.
+#[derive(Debug, Parser)]
+#[command(name = "pgtools-fingerprint", version, about)]
+struct Args {
+    /// Input GFA or GFA.GZ file, or - for stdin
+    #[arg(value_name = "GFA")]
+    input: PathBuf,
+
+    /// Compare against an expected fingerprint and exit non-zero on mismatch
+    #[arg(long, value_name = "FINGERPRINT")]
+    verify: Option<String>,
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    let fingerprint = compute_fingerprint_from_path(&args.input)?.to_string();
+
+    match args.verify {
+        Some(expected) if expected.trim() != fingerprint => {
+            bail!(
+                "fingerprint mismatch: expected {}, got {fingerprint}",
+                expected.trim()
+            );
+        }
+        Some(_) => println!("OK {fingerprint}"),
+        None => println!("{fingerprint}"),
+    }
+
+    Ok(())
+}
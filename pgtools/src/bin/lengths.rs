@@ -0,0 +1,37 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::Parser;
+use pgtools::{open_gfa_reader, write_segment_lengths};
+
+/// Emit every segment length of a GFA or GFA.GZ file, one per line.
+#[derive(Debug, Parser)]
+#[command(name = "pgtools-lengths", version, about)]
+struct Args {
+    /// Input GFA or GFA.GZ file, or - for stdin
+    #[arg(short, long, value_name = "GFA")]
+    input: PathBuf,
+
+    /// Output file (defaults to stdout)
+    #[arg(short, long, value_name = "FILE")]
+    output: Option<PathBuf>,
+
+    /// Sort lengths in descending order (buffers all lengths in memory)
+    #[arg(long)]
+    sorted: bool,
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    let reader = open_gfa_reader(&args.input)?;
+
+    let mut out: Box<dyn Write> = match &args.output {
+        Some(path) => Box::new(BufWriter::new(File::create(path)?)),
+        None => Box::new(BufWriter::new(io::stdout().lock())),
+    };
+
+    write_segment_lengths(reader, &mut out, args.sorted)?;
+    Ok(())
+}
@@ -0,0 +1,46 @@
+use std::path::PathBuf;
+
+use anyhow::Result;
+use clap::Parser;
+use pgtools::{open_gfa_reader, split_by_record_type};
+
+/// Split a GFA or GFA.GZ file into segments.gfa, links.gfa and paths.gfa.
+#[derive(Debug, Parser)]
+#[command(name = "pgtools-split-types", version, about)]
+struct Args {
+    /// Input GFA or GFA.GZ file, or - for stdin
+    #[arg(short, long, value_name = "GFA")]
+    input: PathBuf,
+
+    /// Output directory (created if missing)
+    #[arg(long, value_name = "DIR")]
+    outdir: PathBuf,
+
+    /// Output JSON instead of human-readable text
+    #[arg(long)]
+    json: bool,
+}
+
+fn main() -> Result<()> {
+    let args = Args::parse();
+    let reader = open_gfa_reader(&args.input)?;
+    let counts = split_by_record_type(reader, &args.outdir)?;
+
+    if args.json {
+        println!("{}", serde_json::to_string_pretty(&counts)?);
+        return Ok(());
+    }
+
+    println!(
+        "Split {} into {}",
+        args.input.display(),
+        args.outdir.display()
+    );
+    println!("-----------------------------------------");
+    println!("Segments (S)       : {}", counts.segments);
+    println!("Links (L)          : {}", counts.links);
+    println!("Paths (P)          : {}", counts.paths);
+    println!("Other lines        : {}", counts.other);
+
+    Ok(())
+}
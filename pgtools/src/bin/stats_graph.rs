@@ -2,47 +2,137 @@ use std::path::PathBuf;
 
 use anyhow::Result;
 use clap::Parser;
-use pgtools::compute_graph_stats_from_path;
-use serde_json;
+use pgtools::{compute_graph_stats_from_path_with_genome_size_bounded, stats_to_tsv};
 
 /// Graph topology statistics (N50, degrees, branching, etc.)
 #[derive(Debug, Parser)]
 #[command(name = "pgtools-stats-graph", version, about)]
 struct Args {
-    /// Input GFA or GFA.GZ file
+    /// Input GFA or GFA.GZ file, or - for stdin
     #[arg(value_name = "GFA")]
     input: PathBuf,
 
+    /// Genome size for NG50 / LG50 (e.g. 3200000000)
+    #[arg(long, value_name = "BP")]
+    genome_size: Option<u64>,
+
+    /// Abort if any line is longer than this many bytes
+    #[arg(long, value_name = "BYTES")]
+    max_line_length: Option<usize>,
+
     /// Output JSON instead of human-readable text
     #[arg(long)]
     json: bool,
+
+    /// Output metric<TAB>value rows instead of human-readable text
+    #[arg(long, conflicts_with = "json")]
+    tsv: bool,
 }
 
 fn main() -> Result<()> {
     let args = Args::parse();
-    let stats = compute_graph_stats_from_path(&args.input)?;
+    let stats = compute_graph_stats_from_path_with_genome_size_bounded(
+        &args.input,
+        args.genome_size,
+        args.max_line_length,
+    )?;
 
     if args.json {
         println!("{}", serde_json::to_string_pretty(&stats)?);
         return Ok(());
     }
 
+    if args.tsv {
+        print!("{}", stats_to_tsv(&serde_json::to_value(&stats)?));
+        return Ok(());
+    }
+
     println!("Graph stats for {}", args.input.display());
     println!("-----------------------------------------");
     println!("Segments (S)        : {}", stats.basic.node_count);
     println!("Edges (L)           : {}", stats.basic.edge_count);
+    println!("Containments (C)    : {}", stats.basic.containment_count);
     println!("Other records       : {}", stats.basic.other_records);
     println!();
     println!("Total bp            : {}", stats.basic.total_bp);
     println!("Segment N50         : {}", stats.n50);
     println!("Segment L50         : {}", stats.l50);
+    println!("Segment N90         : {}", stats.n90);
+    println!("Segment L90         : {}", stats.l90);
+    if let (Some(ng50), Some(lg50)) = (stats.ng50, stats.lg50) {
+        println!("Segment NG50        : {}", ng50);
+        println!("Segment LG50        : {}", lg50);
+    }
     println!("Mean segment length : {:.2}", stats.basic.mean_node_len());
     println!();
     println!("Branching nodes (deg>2): {}", stats.branching_nodes);
+    println!("Tips (deg=1)           : {}", stats.tip_count);
+    println!("Isolated nodes (deg=0) : {}", stats.isolated_node_count);
+    println!("Linked on both ends    : {}", stats.two_sided_nodes);
+    println!("Linked on + end only   : {}", stats.plus_side_only_nodes);
+    println!("Linked on - end only   : {}", stats.minus_side_only_nodes);
     println!("Degree histogram (deg -> count):");
     for (deg, count) in &stats.degree_histogram {
         println!("  {} -> {}", deg, count);
     }
+    println!("+ end degree histogram (deg -> count):");
+    for (deg, count) in &stats.plus_side_degree_histogram {
+        println!("  {} -> {}", deg, count);
+    }
+    println!("- end degree histogram (deg -> count):");
+    for (deg, count) in &stats.minus_side_degree_histogram {
+        println!("  {} -> {}", deg, count);
+    }
+    println!();
+    println!("--- Graph Anomalies ---");
+    println!("Self-loops          : {}", stats.self_loop_count);
+    println!("Multi-edges         : {}", stats.multi_edge_count);
+    println!("Duplicate segments  : {}", stats.duplicate_segment_count);
+    println!("Overlap violations  : {}", stats.overlap_violations.len());
+    for v in &stats.overlap_violations {
+        println!(
+            "  {}{} -> {}{} {}: {} / {} bp over segments of {} / {} bp",
+            v.from,
+            v.from_orient,
+            v.to,
+            v.to_orient,
+            v.overlap,
+            v.from_overlap_bp,
+            v.to_overlap_bp,
+            v.from_len,
+            v.to_len
+        );
+    }
+    println!("Unknown path steps  : {}", stats.unknown_path_steps);
+    println!(
+        "Orientation mismatches (links vs paths): {}",
+        stats.orientation_mismatches.len()
+    );
+    for name in &stats.orientation_mismatches {
+        println!("  {}", name);
+    }
+    println!();
+    println!("Paths (P)           : {}", stats.basic.path_count);
+    println!("Paths with repeats  : {}", stats.paths_with_repeats);
+    if !stats.path_step_histogram.is_empty() {
+        println!("Path step histogram (steps -> paths):");
+        for (steps, count) in &stats.path_step_histogram {
+            println!("  {} -> {}", steps, count);
+        }
+        let classes = &stats.pangenome_classes;
+        println!("Pangenome classes (segments / bp):");
+        for (name, tally) in [
+            ("core", &classes.core),
+            ("shell", &classes.shell),
+            ("cloud", &classes.cloud),
+        ] {
+            println!("  {:<5} : {} / {}", name, tally.segments, tally.bp);
+        }
+        println!("Node depth histogram (depth -> nodes):");
+        for (depth, count) in &stats.node_depth_histogram {
+            println!("  {} -> {}", depth, count);
+        }
+    }
 
     Ok(())
 }
@@ -5,7 +5,6 @@ use std::process::Command;
 use anyhow::{anyhow, Result};
 use clap::Parser;
 use serde::Serialize;
-use serde_json;
 
 #[derive(Debug, Serialize)]
 struct SampleSummary {
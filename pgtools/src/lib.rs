@@ -1,23 +1,24 @@
 // ================== Imports ==================
 
 use std::fs::File;
-use std::io::{self, BufRead, BufReader};
+use std::io::{self, BufRead, BufReader, BufWriter, Read, Seek, SeekFrom, Write};
 use std::path::Path;
 
 use flate2::read::GzDecoder;
 use indicatif::{ProgressBar, ProgressStyle};
-use serde::Serialize;
+use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
 // ================== BasicStats struct ==================
 
-#[derive(Debug, Clone, Serialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct BasicStats {
     pub total_lines: u64,
 
     pub node_count: u64,
     pub edge_count: u64,
     pub path_count: u64,
+    pub containment_count: u64,
     pub other_records: u64,
     pub comment_lines: u64,
 
@@ -27,6 +28,7 @@ pub struct BasicStats {
 
     pub gc_bases: u64,
     pub n_bases: u64,
+    pub soft_masked_bases: u64,
 }
 
 impl Default for BasicStats {
@@ -36,6 +38,7 @@ impl Default for BasicStats {
             node_count: 0,
             edge_count: 0,
             path_count: 0,
+            containment_count: 0,
             other_records: 0,
             comment_lines: 0,
             total_bp: 0,
@@ -43,10 +46,19 @@ impl Default for BasicStats {
             max_node_len: 0,
             gc_bases: 0,
             n_bases: 0,
+            soft_masked_bases: 0,
         }
     }
 }
 
+/// `BasicStats` fields in declaration order, followed by `mean_node_len`.
+#[derive(Debug, Serialize)]
+pub struct BasicStatsSummary<'a> {
+    #[serde(flatten)]
+    pub basic: &'a BasicStats,
+    pub mean_node_len: f64,
+}
+
 impl BasicStats {
     pub fn mean_node_len(&self) -> f64 {
         if self.node_count == 0 {
@@ -56,6 +68,36 @@ impl BasicStats {
         }
     }
 
+    /// Serializable view of the stats, including the derived
+    /// `mean_node_len` that isn't a stored field.
+    pub fn summary(&self) -> BasicStatsSummary<'_> {
+        BasicStatsSummary {
+            basic: self,
+            mean_node_len: self.mean_node_len(),
+        }
+    }
+
+    /// `summary` as a JSON value. `serde_json::Value` sorts its keys, so
+    /// serialize `summary` directly where field order matters.
+    pub fn summary_json(&self) -> serde_json::Value {
+        serde_json::json!(self.summary())
+    }
+
+    /// `serialize_with` helper so structs embedding `BasicStats` emit the
+    /// same view as `summary`.
+    fn serialize_summary<S: serde::Serializer>(
+        stats: &BasicStats,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        stats.summary().serialize(serializer)
+    }
+
+    fn add_composition(&mut self, composition: &SequenceStats) {
+        self.gc_bases += composition.gc;
+        self.n_bases += composition.n;
+        self.soft_masked_bases += composition.soft_masked;
+    }
+
     pub fn normalized(self) -> Self {
         if self.node_count == 0 {
             Self {
@@ -68,6 +110,38 @@ impl BasicStats {
     }
 }
 
+// ================== Sequence composition ==================
+
+/// Base composition counters shared by every stats reader, so GC/N/soft-mask
+/// counting is identical everywhere.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SequenceStats {
+    // G/C bases, either case
+    pub gc: u64,
+    // N bases, either case
+    pub n: u64,
+    // lowercase (soft-masked) letters
+    pub soft_masked: u64,
+    // all bytes observed
+    pub total: u64,
+}
+
+impl SequenceStats {
+    pub fn observe(&mut self, seq: &[u8]) {
+        for b in seq {
+            match b {
+                b'G' | b'g' | b'C' | b'c' => self.gc += 1,
+                b'N' | b'n' => self.n += 1,
+                _ => {}
+            }
+            if b.is_ascii_lowercase() {
+                self.soft_masked += 1;
+            }
+        }
+        self.total += seq.len() as u64;
+    }
+}
+
 // ================== Error type ==================
 
 #[derive(Error, Debug)]
@@ -77,53 +151,185 @@ pub enum GfaError {
 
     #[error("Malformed GFA line: {0}")]
     MalformedLine(String),
+
+    #[error("{}", render_field_error(line, *field, reason))]
+    MalformedField {
+        line: String,
+        // 0-based tab-separated field index; may be past the end if missing
+        field: usize,
+        reason: String,
+    },
+
+    #[error("Line {line_number} exceeds the maximum line length of {max_length} bytes")]
+    LineTooLong { line_number: u64, max_length: usize },
+
+    #[error("Invalid CIGAR string: {0}")]
+    InvalidCigar(String),
+
+    #[error("Checkpoint error: {0}")]
+    Checkpoint(serde_json::Error),
+}
+
+fn field_error(line: &str, field: usize, reason: impl Into<String>) -> GfaError {
+    GfaError::MalformedField {
+        line: line.to_string(),
+        field,
+        reason: reason.into(),
+    }
+}
+
+/// Print the offending line with a caret run under `field` (or just past the
+/// end of the line when the field is missing). Tabs before the field are
+/// mirrored in the caret line so it stays aligned in a terminal.
+fn render_field_error(line: &str, field: usize, reason: &str) -> String {
+    let mut start = 0;
+    let mut width = 0;
+    let mut offset = 0;
+    let mut found = false;
+
+    for (i, f) in line.split('\t').enumerate() {
+        if i == field {
+            start = offset;
+            width = f.len();
+            found = true;
+            break;
+        }
+        offset += f.len() + 1;
+    }
+    if !found {
+        start = line.len();
+    }
+
+    let mut marker: String = line[..start]
+        .chars()
+        .map(|c| if c == '\t' { '\t' } else { ' ' })
+        .collect();
+    if !found && !line.is_empty() {
+        marker.push('\t');
+    }
+    marker.push_str(&"^".repeat(width.max(1)));
+
+    format!(
+        "Malformed GFA line (field {}: {reason}):\n{line}\n{marker}",
+        field + 1
+    )
 }
 
-// ================== Reader helper (GFA / GFA.GZ) ==================
+// ================== Reader helper (GFA / GFA.GZ / stdin) ==================
+
+/// Whether `path` is `-`, meaning "read from standard input".
+pub fn is_stdin_path<P: AsRef<Path>>(path: P) -> bool {
+    path.as_ref() == Path::new("-")
+}
 
+/// Open a GFA for streaming: `-` reads standard input, `.gz` files are
+/// decompressed, anything else is read as plain text.
 pub fn open_gfa_reader<P: AsRef<Path>>(path: P) -> Result<Box<dyn BufRead>, GfaError> {
     let path_ref = path.as_ref();
+
+    if is_stdin_path(path_ref) {
+        let mut reader = io::stdin().lock();
+        skip_utf8_bom(&mut reader)?;
+        return Ok(Box::new(reader));
+    }
+
     let file = File::open(path_ref)?;
 
     // Detect .gz filename
     if let Some(ext) = path_ref.extension() {
         if ext == "gz" {
             let decoder = GzDecoder::new(file);
-            return Ok(Box::new(BufReader::new(decoder)));
+            let mut reader = BufReader::new(decoder);
+            skip_utf8_bom(&mut reader)?;
+            return Ok(Box::new(reader));
         }
     }
 
-    Ok(Box::new(BufReader::new(file)))
+    let mut reader = BufReader::new(file);
+    skip_utf8_bom(&mut reader)?;
+    Ok(Box::new(reader))
+}
+
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+/// Consume a leading UTF-8 byte-order mark, as written by some Windows
+/// editors, so the first record isn't read as `\u{feff}H...`. Returns the
+/// number of bytes skipped (0 or 3).
+pub fn skip_utf8_bom<R: BufRead>(reader: &mut R) -> io::Result<usize> {
+    if reader.fill_buf()?.starts_with(UTF8_BOM) {
+        reader.consume(UTF8_BOM.len());
+        return Ok(UTF8_BOM.len());
+    }
+    Ok(0)
 }
 
 // ================== Core compute functions ==================
 
 pub fn compute_basic_stats<R: BufRead>(reader: R) -> Result<BasicStats, GfaError> {
+    compute_basic_stats_bounded(reader, None)
+}
+
+/// Like `compute_basic_stats`, but fails with `GfaError::LineTooLong` as soon
+/// as a line exceeds `max_line_length` bytes instead of buffering it whole.
+pub fn compute_basic_stats_bounded<R: BufRead>(
+    mut reader: R,
+    max_line_length: Option<usize>,
+) -> Result<BasicStats, GfaError> {
+    skip_utf8_bom(&mut reader)?;
+
     let mut stats = BasicStats::default();
+    let mut buf = String::new();
+    let mut line_number: u64 = 0;
 
-    for line_result in reader.lines() {
-        let line = line_result?;
-        process_line(&mut stats, &line)?;
+    loop {
+        buf.clear();
+        line_number += 1;
+        let bytes_read = read_bounded_line(&mut reader, &mut buf, max_line_length, line_number)?;
+        if bytes_read == 0 {
+            break;
+        }
+
+        let line = buf.trim_end_matches('\n');
+        process_line(&mut stats, line)?;
     }
 
     Ok(stats.normalized())
 }
 
 pub fn compute_basic_stats_from_path<P: AsRef<Path>>(path: P) -> Result<BasicStats, GfaError> {
+    compute_basic_stats_from_path_bounded(path, None)
+}
+
+pub fn compute_basic_stats_from_path_bounded<P: AsRef<Path>>(
+    path: P,
+    max_line_length: Option<usize>,
+) -> Result<BasicStats, GfaError> {
     let reader = open_gfa_reader(&path)?;
-    compute_basic_stats(reader)
+    compute_basic_stats_bounded(reader, max_line_length)
 }
 
 pub fn compute_basic_stats_from_path_with_progress<P: AsRef<Path>>(
     path: P,
+) -> Result<BasicStats, GfaError> {
+    compute_basic_stats_from_path_with_progress_bounded(path, None)
+}
+
+pub fn compute_basic_stats_from_path_with_progress_bounded<P: AsRef<Path>>(
+    path: P,
+    max_line_length: Option<usize>,
 ) -> Result<BasicStats, GfaError> {
     let path_ref = path.as_ref();
 
-    let is_gz = path_ref.extension().map_or(false, |e| e == "gz");
+    let is_gz = path_ref.extension().is_some_and(|e| e == "gz");
 
     if is_gz {
         eprintln!("Note: .gz file detected — disabling progress bar.");
-        return compute_basic_stats_from_path(path);
+        return compute_basic_stats_from_path_bounded(path, max_line_length);
+    }
+
+    if is_stdin_path(path_ref) {
+        // Size is unknown, so there is nothing to draw a bar against.
+        return compute_basic_stats_from_path_bounded(path, max_line_length);
     }
 
     let file = File::open(path_ref)?;
@@ -141,12 +347,16 @@ pub fn compute_basic_stats_from_path_with_progress<P: AsRef<Path>>(
     );
 
     let mut reader = BufReader::new(file);
+    pb.inc(skip_utf8_bom(&mut reader)? as u64);
+
     let mut buf = String::new();
     let mut stats = BasicStats::default();
+    let mut line_number: u64 = 0;
 
     loop {
         buf.clear();
-        let bytes_read = reader.read_line(&mut buf)?;
+        line_number += 1;
+        let bytes_read = read_bounded_line(&mut reader, &mut buf, max_line_length, line_number)?;
         if bytes_read == 0 {
             break;
         }
@@ -160,8 +370,500 @@ pub fn compute_basic_stats_from_path_with_progress<P: AsRef<Path>>(
     Ok(stats.normalized())
 }
 
+// ================== Overlap-adjusted stats ==================
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OverlapAdjustedStats {
+    #[serde(serialize_with = "BasicStats::serialize_summary")]
+    pub basic: BasicStats,
+
+    // sum of the M operations of every link overlap
+    pub overlap_bp: u64,
+
+    // basic.total_bp minus overlap_bp: approximate non-redundant length
+    pub adjusted_total_bp: u64,
+}
+
+/// Basic stats plus an approximate non-redundant length: the `M` bases of
+/// each `L` line's overlap CIGAR are subtracted from `total_bp`.
+pub fn compute_basic_stats_overlap_adjusted<R: BufRead>(
+    reader: R,
+) -> Result<OverlapAdjustedStats, GfaError> {
+    compute_basic_stats_overlap_adjusted_bounded(reader, None)
+}
+
+/// `compute_basic_stats_overlap_adjusted` with the `max_line_length` check of
+/// `compute_basic_stats_bounded`.
+pub fn compute_basic_stats_overlap_adjusted_bounded<R: BufRead>(
+    mut reader: R,
+    max_line_length: Option<usize>,
+) -> Result<OverlapAdjustedStats, GfaError> {
+    skip_utf8_bom(&mut reader)?;
+
+    let mut stats = BasicStats::default();
+    let mut overlap_bp: u64 = 0;
+    let mut buf = String::new();
+    let mut line_number: u64 = 0;
+
+    loop {
+        buf.clear();
+        line_number += 1;
+        let bytes_read = read_bounded_line(&mut reader, &mut buf, max_line_length, line_number)?;
+        if bytes_read == 0 {
+            break;
+        }
+
+        let line = buf.trim_end_matches('\n');
+        process_line(&mut stats, line)?;
+
+        let trimmed = line.trim();
+        if trimmed.starts_with('L') {
+            // L <from> <from_orient> <to> <to_orient> <overlap>
+            if let Some(overlap) = trimmed.split('\t').nth(5) {
+                let cigar =
+                    Cigar::parse(overlap).map_err(|e| field_error(trimmed, 5, e.to_string()))?;
+                overlap_bp += cigar.match_length();
+            }
+        }
+    }
+
+    let basic = stats.normalized();
+    Ok(OverlapAdjustedStats {
+        overlap_bp,
+        adjusted_total_bp: basic.total_bp.saturating_sub(overlap_bp),
+        basic,
+    })
+}
+
+pub fn compute_basic_stats_overlap_adjusted_from_path<P: AsRef<Path>>(
+    path: P,
+) -> Result<OverlapAdjustedStats, GfaError> {
+    compute_basic_stats_overlap_adjusted_from_path_bounded(path, None)
+}
+
+pub fn compute_basic_stats_overlap_adjusted_from_path_bounded<P: AsRef<Path>>(
+    path: P,
+    max_line_length: Option<usize>,
+) -> Result<OverlapAdjustedStats, GfaError> {
+    let reader = open_gfa_reader(&path)?;
+    compute_basic_stats_overlap_adjusted_bounded(reader, max_line_length)
+}
+
+// ================== CIGAR overlaps ==================
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum CigarOp {
+    Match,       // M
+    Insertion,   // I
+    Deletion,    // D
+    Skip,        // N
+    SoftClip,    // S
+    HardClip,    // H
+    Padding,     // P
+    SeqMatch,    // =
+    SeqMismatch, // X
+}
+
+impl CigarOp {
+    fn from_char(c: char) -> Option<Self> {
+        Some(match c {
+            'M' => CigarOp::Match,
+            'I' => CigarOp::Insertion,
+            'D' => CigarOp::Deletion,
+            'N' => CigarOp::Skip,
+            'S' => CigarOp::SoftClip,
+            'H' => CigarOp::HardClip,
+            'P' => CigarOp::Padding,
+            '=' => CigarOp::SeqMatch,
+            'X' => CigarOp::SeqMismatch,
+            _ => return None,
+        })
+    }
+
+    fn consumes_reference(self) -> bool {
+        matches!(
+            self,
+            CigarOp::Match
+                | CigarOp::Deletion
+                | CigarOp::Skip
+                | CigarOp::SeqMatch
+                | CigarOp::SeqMismatch
+        )
+    }
+
+    fn consumes_query(self) -> bool {
+        matches!(
+            self,
+            CigarOp::Match
+                | CigarOp::Insertion
+                | CigarOp::SoftClip
+                | CigarOp::SeqMatch
+                | CigarOp::SeqMismatch
+        )
+    }
+}
+
+/// A parsed overlap CIGAR such as `47M2D3M`. `*` parses to no operations.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct Cigar {
+    pub ops: Vec<(u32, CigarOp)>,
+}
+
+impl Cigar {
+    pub fn parse(s: &str) -> Result<Cigar, GfaError> {
+        let invalid = || GfaError::InvalidCigar(s.to_string());
+
+        if s == "*" {
+            return Ok(Cigar::default());
+        }
+        if s.is_empty() {
+            return Err(invalid());
+        }
+
+        let mut ops = Vec::new();
+        let mut num: u32 = 0;
+        let mut have_digits = false;
+
+        for c in s.chars() {
+            if let Some(d) = c.to_digit(10) {
+                num = num
+                    .checked_mul(10)
+                    .and_then(|n| n.checked_add(d))
+                    .ok_or_else(invalid)?;
+                have_digits = true;
+                continue;
+            }
+            if !have_digits {
+                return Err(invalid());
+            }
+            let op = CigarOp::from_char(c).ok_or_else(invalid)?;
+            ops.push((num, op));
+            num = 0;
+            have_digits = false;
+        }
+
+        if have_digits {
+            return Err(invalid());
+        }
+        Ok(Cigar { ops })
+    }
+
+    /// Bases consumed on the reference (first) side: M, D, N, =, X.
+    pub fn reference_length(&self) -> u64 {
+        self.length_where(CigarOp::consumes_reference)
+    }
+
+    /// Bases consumed on the query (second) side: M, I, S, =, X.
+    pub fn query_length(&self) -> u64 {
+        self.length_where(CigarOp::consumes_query)
+    }
+
+    /// Total length of the `M` operations.
+    pub fn match_length(&self) -> u64 {
+        self.length_where(|op| op == CigarOp::Match)
+    }
+
+    fn length_where(&self, pred: impl Fn(CigarOp) -> bool) -> u64 {
+        self.ops
+            .iter()
+            .filter(|(_, op)| pred(*op))
+            .map(|(len, _)| *len as u64)
+            .sum()
+    }
+}
+
+// ================== Resumable stats (checkpointing) ==================
+
+/// Default interval between checkpoints for `compute_basic_stats_resumable`.
+pub const CHECKPOINT_INTERVAL_BYTES: u64 = 64 * 1024 * 1024;
+
+/// Bytes at the start of the input hashed into a checkpoint's identity.
+const CHECKPOINT_PREFIX_BYTES: u64 = 1024 * 1024;
+
+#[derive(Debug, Serialize, Deserialize)]
+struct StatsCheckpoint {
+    // the input the checkpoint was taken on (None in older checkpoints,
+    // which are never resumed)
+    #[serde(default)]
+    input: Option<InputIdentity>,
+    offset: u64,
+    // lines consumed before `offset`, so errors keep their line numbers
+    #[serde(default)]
+    line_number: u64,
+    stats: BasicStats,
+}
+
+/// Enough about the input to tell whether a checkpoint still applies: an
+/// in-place edit changes the mtime and usually the prefix hash even when
+/// the length stays the same.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct InputIdentity {
+    file_len: u64,
+    // mtime as (seconds, nanoseconds) since the Unix epoch, if available
+    modified: Option<(u64, u32)>,
+    // FNV-1a of the first CHECKPOINT_PREFIX_BYTES bytes
+    prefix_hash: u64,
+}
+
+impl InputIdentity {
+    fn of(file: &mut File) -> Result<Self, GfaError> {
+        let metadata = file.metadata()?;
+        let modified = metadata
+            .modified()
+            .ok()
+            .and_then(|t| t.duration_since(std::time::UNIX_EPOCH).ok())
+            .map(|d| (d.as_secs(), d.subsec_nanos()));
+
+        file.seek(SeekFrom::Start(0))?;
+        let mut prefix = BufReader::new((&mut *file).take(CHECKPOINT_PREFIX_BYTES));
+        let mut prefix_hash = FNV_OFFSET_BASIS;
+        loop {
+            let chunk = prefix.fill_buf()?;
+            if chunk.is_empty() {
+                break;
+            }
+            prefix_hash = fnv1a(prefix_hash, chunk);
+            let len = chunk.len();
+            prefix.consume(len);
+        }
+
+        Ok(InputIdentity {
+            file_len: metadata.len(),
+            modified,
+            prefix_hash,
+        })
+    }
+}
+
+/// Compute basic stats, writing a checkpoint every
+/// `CHECKPOINT_INTERVAL_BYTES` so an interrupted run can resume.
+pub fn compute_basic_stats_resumable<P: AsRef<Path>, Q: AsRef<Path>>(
+    path: P,
+    checkpoint_path: Q,
+) -> Result<BasicStats, GfaError> {
+    compute_basic_stats_resumable_bounded(path, checkpoint_path, None)
+}
+
+pub fn compute_basic_stats_resumable_bounded<P: AsRef<Path>, Q: AsRef<Path>>(
+    path: P,
+    checkpoint_path: Q,
+    max_line_length: Option<usize>,
+) -> Result<BasicStats, GfaError> {
+    compute_basic_stats_resumable_every(
+        path,
+        checkpoint_path,
+        CHECKPOINT_INTERVAL_BYTES,
+        max_line_length,
+    )
+}
+
+/// Like `compute_basic_stats_resumable_bounded` with an explicit checkpoint
+/// interval.
+///
+/// If `checkpoint_path` holds a checkpoint for the same file (length, mtime
+/// and a hash of its first `CHECKPOINT_PREFIX_BYTES` bytes all match),
+/// reading resumes from its saved byte offset with its saved counters; any
+/// other checkpoint is ignored and the run starts over. The
+/// checkpoint is removed once the file has been fully processed.
+/// Gzipped input and stdin are not seekable and are processed from the
+/// start without checkpoints.
+pub fn compute_basic_stats_resumable_every<P: AsRef<Path>, Q: AsRef<Path>>(
+    path: P,
+    checkpoint_path: Q,
+    interval_bytes: u64,
+    max_line_length: Option<usize>,
+) -> Result<BasicStats, GfaError> {
+    let path_ref = path.as_ref();
+    let checkpoint_path = checkpoint_path.as_ref();
+
+    if path_ref.extension().is_some_and(|e| e == "gz") {
+        eprintln!("Note: .gz file detected — checkpointing disabled.");
+        return compute_basic_stats_from_path_bounded(path, max_line_length);
+    }
+
+    if is_stdin_path(path_ref) {
+        eprintln!("Note: reading from stdin — checkpointing disabled.");
+        return compute_basic_stats_from_path_bounded(path, max_line_length);
+    }
+
+    let mut file = File::open(path_ref)?;
+    let identity = InputIdentity::of(&mut file)?;
+
+    let (mut offset, mut line_number, mut stats) = match read_checkpoint(checkpoint_path)? {
+        Some(cp) if cp.input.as_ref() == Some(&identity) && cp.offset <= identity.file_len => {
+            (cp.offset, cp.line_number, cp.stats)
+        }
+        _ => (0, 0, BasicStats::default()),
+    };
+
+    file.seek(SeekFrom::Start(offset))?;
+    let mut reader = BufReader::new(file);
+    if offset == 0 {
+        offset += skip_utf8_bom(&mut reader)? as u64;
+    }
+
+    let mut buf = String::new();
+    let mut last_checkpoint = offset;
+
+    loop {
+        buf.clear();
+        line_number += 1;
+        let bytes_read = read_bounded_line(&mut reader, &mut buf, max_line_length, line_number)?;
+        if bytes_read == 0 {
+            break;
+        }
+
+        offset += bytes_read as u64;
+        let line = buf.trim_end_matches('\n');
+        process_line(&mut stats, line)?;
+
+        if offset - last_checkpoint >= interval_bytes {
+            write_checkpoint(
+                checkpoint_path,
+                &StatsCheckpoint {
+                    input: Some(identity.clone()),
+                    offset,
+                    line_number,
+                    stats: stats.clone(),
+                },
+            )?;
+            last_checkpoint = offset;
+        }
+    }
+
+    if checkpoint_path.exists() {
+        std::fs::remove_file(checkpoint_path)?;
+    }
+
+    Ok(stats.normalized())
+}
+
+fn read_checkpoint(path: &Path) -> Result<Option<StatsCheckpoint>, GfaError> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    let file = File::open(path)?;
+    let checkpoint = serde_json::from_reader(BufReader::new(file)).map_err(GfaError::Checkpoint)?;
+    Ok(Some(checkpoint))
+}
+
+fn write_checkpoint(path: &Path, checkpoint: &StatsCheckpoint) -> Result<(), GfaError> {
+    // Write to a sibling temp file and rename, so a crash mid-write never
+    // leaves a truncated checkpoint behind.
+    let mut tmp_name = path.as_os_str().to_owned();
+    tmp_name.push(".tmp");
+    let tmp_path = std::path::PathBuf::from(tmp_name);
+
+    let mut writer = BufWriter::new(File::create(&tmp_path)?);
+    serde_json::to_writer(&mut writer, checkpoint).map_err(GfaError::Checkpoint)?;
+    writer.flush()?;
+    drop(writer);
+
+    std::fs::rename(&tmp_path, path)?;
+    Ok(())
+}
+
+// ================== Watch mode ==================
+
+/// Size and mtime of a file, compared between polls by `watch_for_changes`.
+pub type FileSignature = (u64, Option<std::time::SystemTime>);
+
+pub fn file_signature<P: AsRef<Path>>(path: P) -> Result<FileSignature, GfaError> {
+    let metadata = std::fs::metadata(path)?;
+    Ok((metadata.len(), metadata.modified().ok()))
+}
+
+/// Call `on_change` on the first poll and whenever `poll` returns a
+/// signature different from the previous one, calling `wait` between polls.
+///
+/// A file that is still being written often ends in a half-written line, so
+/// an `on_change` error doesn't stop the watch: it is handed to `report` and
+/// the next change is tried again. Errors from `poll` itself are returned.
+/// Stops once `wait` returns false and reports how many times `on_change`
+/// succeeded; with a `wait` that always sleeps and returns true it runs
+/// until the process is interrupted.
+pub fn watch_for_changes<S, E, P, W, F, R>(
+    mut poll: P,
+    mut wait: W,
+    mut on_change: F,
+    mut report: R,
+) -> Result<u64, E>
+where
+    S: PartialEq,
+    P: FnMut() -> Result<S, E>,
+    W: FnMut() -> bool,
+    F: FnMut() -> Result<(), E>,
+    R: FnMut(E),
+{
+    let mut last_seen: Option<S> = None;
+    let mut runs: u64 = 0;
+
+    loop {
+        let current = poll()?;
+        if last_seen.as_ref() != Some(&current) {
+            match on_change() {
+                Ok(()) => runs += 1,
+                Err(err) => report(err),
+            }
+            last_seen = Some(current);
+        }
+        if !wait() {
+            return Ok(runs);
+        }
+    }
+}
+
 // ================== Line parsing ==================
 
+/// Read one line (including its newline) into `buf`, returning the number of
+/// bytes consumed, or 0 at EOF. With a `max_line_length`, the line is read
+/// chunk by chunk from the reader's buffer and rejected once its content
+/// grows past the limit, so a corrupt file without newlines cannot exhaust
+/// memory.
+fn read_bounded_line<R: BufRead>(
+    reader: &mut R,
+    buf: &mut String,
+    max_line_length: Option<usize>,
+    line_number: u64,
+) -> Result<usize, GfaError> {
+    let max_length = match max_line_length {
+        Some(max_length) => max_length,
+        None => return Ok(reader.read_line(buf)?),
+    };
+
+    let mut bytes: Vec<u8> = Vec::new();
+    loop {
+        let available = reader.fill_buf()?;
+        if available.is_empty() {
+            break;
+        }
+
+        let (chunk_len, found_newline) = match available.iter().position(|&b| b == b'\n') {
+            Some(i) => (i + 1, true),
+            None => (available.len(), false),
+        };
+
+        let content_len = bytes.len() + chunk_len - usize::from(found_newline);
+        if content_len > max_length {
+            return Err(GfaError::LineTooLong {
+                line_number,
+                max_length,
+            });
+        }
+
+        bytes.extend_from_slice(&available[..chunk_len]);
+        reader.consume(chunk_len);
+        if found_newline {
+            break;
+        }
+    }
+
+    let line =
+        std::str::from_utf8(&bytes).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    buf.push_str(line);
+    Ok(bytes.len())
+}
+
 fn process_line(stats: &mut BasicStats, line: &str) -> Result<(), GfaError> {
     let trimmed = line.trim();
     if trimmed.is_empty() {
@@ -183,7 +885,7 @@ fn process_line(stats: &mut BasicStats, line: &str) -> Result<(), GfaError> {
     match record_type {
         'S' => {
             stats.node_count += 1;
-            handle_segment_line(stats, trimmed, line)?;
+            handle_segment_line(stats, trimmed)?;
         }
         'L' => {
             stats.edge_count += 1;
@@ -191,6 +893,10 @@ fn process_line(stats: &mut BasicStats, line: &str) -> Result<(), GfaError> {
         'P' => {
             stats.path_count += 1;
         }
+        'C' => {
+            check_containment_line(trimmed)?;
+            stats.containment_count += 1;
+        }
         _ => {
             stats.other_records += 1;
         }
@@ -199,20 +905,15 @@ fn process_line(stats: &mut BasicStats, line: &str) -> Result<(), GfaError> {
     Ok(())
 }
 
-fn handle_segment_line(
-    stats: &mut BasicStats,
-    trimmed: &str,
-    original_line: &str,
-) -> Result<(), GfaError> {
+fn handle_segment_line(stats: &mut BasicStats, trimmed: &str) -> Result<(), GfaError> {
     let mut fields = trimmed.split('\t');
     let _s = fields.next();
     let _sid = fields.next();
     let seq = fields
         .next()
-        .ok_or_else(|| GfaError::MalformedLine(original_line.to_string()))?;
+        .ok_or_else(|| field_error(trimmed, 2, "missing sequence"))?;
 
-    if seq != "*" {
-        let len = seq.len() as u64;
+    if let Some(len) = segment_length(seq, fields, trimmed)? {
         stats.total_bp += len;
 
         if len < stats.min_node_len {
@@ -221,42 +922,238 @@ fn handle_segment_line(
         if len > stats.max_node_len {
             stats.max_node_len = len;
         }
+    }
 
-        for b in seq.as_bytes() {
-            match b {
-                b'G' | b'g' | b'C' | b'c' => stats.gc_bases += 1,
-                b'N' | b'n' => stats.n_bases += 1,
-                _ => {}
-            }
-        }
+    if seq != "*" {
+        let mut composition = SequenceStats::default();
+        composition.observe(seq.as_bytes());
+        stats.add_composition(&composition);
     }
 
     Ok(())
 }
 
-use hashbrown::HashMap;
+/// A containment line needs all of `C <container> <orient> <contained>
+/// <orient> <pos> <overlap>`.
+fn check_containment_line(trimmed: &str) -> Result<(), GfaError> {
+    let fields = trimmed.split('\t').count();
+    if fields < 7 {
+        let reason = "containment needs 6 fields after C";
+        return Err(field_error(trimmed, fields, reason));
+    }
+    Ok(())
+}
+
+/// Length of a segment: the sequence length, or the `LN:i:` tag value when
+/// the sequence is `*`. `None` if the sequence is `*` and there is no `LN`.
+/// `tags` are the fields after the sequence; `line` is only used for errors.
+fn segment_length<'a, I>(seq: &str, tags: I, line: &str) -> Result<Option<u64>, GfaError>
+where
+    I: Iterator<Item = &'a str>,
+{
+    if seq != "*" {
+        return Ok(Some(seq.len() as u64));
+    }
+
+    for (i, tag) in tags.enumerate() {
+        if let Some(value) = tag.strip_prefix("LN:i:") {
+            let len = value
+                .parse::<u64>()
+                .map_err(|_| field_error(line, 3 + i, "LN:i: value is not an integer"))?;
+            return Ok(Some(len));
+        }
+    }
+
+    Ok(None)
+}
+
+use hashbrown::{HashMap, HashSet};
 
 // ================== Graph topology stats (Phase 2A) ==================
 
 #[derive(Debug, Clone, serde::Serialize)]
 pub struct GraphStats {
+    #[serde(serialize_with = "BasicStats::serialize_summary")]
     pub basic: BasicStats,
 
     // N50 / L50 on node lengths
     pub n50: u64,
     pub l50: u64,
 
+    // N90 / L90 on node lengths
+    pub n90: u64,
+    pub l90: u64,
+
+    // NG50 / LG50 against a supplied genome size (None when not requested)
+    pub ng50: Option<u64>,
+    pub lg50: Option<u64>,
+
     // degree histogram: (total_degree, count_of_nodes_with_that_degree)
     pub degree_histogram: Vec<(u32, u64)>,
 
     // number of nodes with total degree > 2 (branching)
     pub branching_nodes: u64,
-}
 
-#[derive(Debug, Default)]
-struct NodeDegree {
-    indegree: u32,
-    outdegree: u32,
+    // nodes with total degree 1 (tips) and 0 (isolated)
+    pub tip_count: u64,
+    pub isolated_node_count: u64,
+
+    // per-end degree histograms: (links on the + (3') / - (5') end, count_of_nodes)
+    pub plus_side_degree_histogram: Vec<(u32, u64)>,
+    pub minus_side_degree_histogram: Vec<(u32, u64)>,
+
+    // linked nodes by which ends carry links: both, only the + end, only
+    // the - end
+    pub two_sided_nodes: u64,
+    pub plus_side_only_nodes: u64,
+    pub minus_side_only_nodes: u64,
+
+    // path step-count histogram: (steps, count_of_paths_with_that_many_steps)
+    pub path_step_histogram: Vec<(u64, u64)>,
+
+    // links whose from and to segment are the same
+    pub self_loop_count: u64,
+
+    // distinct (from, from_orient, to, to_orient) links seen more than once
+    pub multi_edge_count: u64,
+
+    // S lines repeating an already-seen segment name
+    pub duplicate_segment_count: u64,
+
+    // links whose overlap CIGAR consumes more bases than a segment has
+    pub overlap_violations: Vec<OverlapViolation>,
+
+    // node depth histogram: (path steps on the node, count_of_nodes)
+    pub node_depth_histogram: Vec<(u32, u64)>,
+
+    // nodes traversed by every path / by exactly one path (and not core);
+    // the segment counts of pangenome_classes.core and .cloud
+    pub core_segments: u64,
+    pub private_segments: u64,
+
+    // path steps naming a segment with no S line
+    pub unknown_path_steps: u64,
+
+    // segments used in only one orientation by links and only the opposite
+    // one by paths (sorted by name)
+    pub orientation_mismatches: Vec<String>,
+
+    // paths visiting at least one segment more than once
+    pub paths_with_repeats: u64,
+
+    // segments split by how many paths traverse them
+    pub pangenome_classes: PangenomeClasses,
+}
+
+/// A link whose overlap is longer than one of the segments it joins.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct OverlapViolation {
+    pub from: String,
+    pub from_orient: String,
+    pub to: String,
+    pub to_orient: String,
+    pub overlap: String,
+
+    // bases the overlap consumes on each side (CIGAR reference / query length)
+    pub from_overlap_bp: u64,
+    pub to_overlap_bp: u64,
+
+    // lengths of the two segments
+    pub from_len: u64,
+    pub to_len: u64,
+}
+
+impl OverlapViolation {
+    fn is_violated(&self) -> bool {
+        self.from_overlap_bp > self.from_len || self.to_overlap_bp > self.to_len
+    }
+}
+
+/// Core / shell / cloud split of the path-covered segments. Classes are
+/// disjoint: with a single path every covered segment is core.
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct PangenomeClasses {
+    // traversed by every path
+    pub core: ClassTally,
+    // traversed by more than one path, but not all
+    pub shell: ClassTally,
+    // traversed by exactly one path
+    pub cloud: ClassTally,
+}
+
+#[derive(Debug, Clone, Default, serde::Serialize)]
+pub struct ClassTally {
+    pub segments: u64,
+    pub bp: u64,
+}
+
+impl ClassTally {
+    fn add(&mut self, bp: u64) {
+        self.segments += 1;
+        self.bp += bp;
+    }
+}
+
+#[derive(Debug, Default)]
+struct NodeDegree {
+    indegree: u32,
+    outdegree: u32,
+
+    // link endpoints on the + (3') and - (5') end, from L orientations
+    plus_side: u32,
+    minus_side: u32,
+
+    // path steps landing on this node, and how many distinct paths those are
+    depth: u32,
+    paths: u32,
+    // ordinal (1-based) of the last path that visited the node
+    last_path: u64,
+
+    // orientations (ORIENT_FORWARD / ORIENT_REVERSE bits) the node is used
+    // in by L lines and by P steps
+    link_orients: u8,
+    path_orients: u8,
+
+    // an S line has been seen for this node
+    defined: bool,
+    // segment length from that S line (None when unknown)
+    length: Option<u64>,
+}
+
+const ORIENT_FORWARD: u8 = 1;
+const ORIENT_REVERSE: u8 = 2;
+
+fn orient_bit(orient: Option<&str>) -> u8 {
+    if orient == Some("-") {
+        ORIENT_REVERSE
+    } else {
+        ORIENT_FORWARD
+    }
+}
+
+/// Segment names interned to dense ids, so per-link state can be keyed on
+/// integers instead of owned strings.
+#[derive(Debug, Default)]
+struct NodeTable {
+    ids: HashMap<String, u32>,
+    nodes: Vec<NodeDegree>,
+}
+
+impl NodeTable {
+    fn id(&mut self, name: &str) -> u32 {
+        if let Some(&id) = self.ids.get(name) {
+            return id;
+        }
+        let id = self.nodes.len() as u32;
+        self.ids.insert(name.to_string(), id);
+        self.nodes.push(NodeDegree::default());
+        id
+    }
+
+    fn node(&mut self, name: &str) -> &mut NodeDegree {
+        let id = self.id(name);
+        &mut self.nodes[id as usize]
+    }
 }
 
 pub fn compute_graph_stats_from_path<P: AsRef<Path>>(
@@ -267,12 +1164,69 @@ pub fn compute_graph_stats_from_path<P: AsRef<Path>>(
 }
 
 pub fn compute_graph_stats<R: BufRead>(reader: R) -> Result<GraphStats, GfaError> {
+    compute_graph_stats_with_genome_size(reader, None)
+}
+
+pub fn compute_graph_stats_from_path_with_genome_size<P: AsRef<Path>>(
+    path: P,
+    genome_size: Option<u64>,
+) -> Result<GraphStats, GfaError> {
+    compute_graph_stats_from_path_with_genome_size_bounded(path, genome_size, None)
+}
+
+pub fn compute_graph_stats_from_path_with_genome_size_bounded<P: AsRef<Path>>(
+    path: P,
+    genome_size: Option<u64>,
+    max_line_length: Option<usize>,
+) -> Result<GraphStats, GfaError> {
+    let reader = open_gfa_reader(&path)?;
+    compute_graph_stats_with_genome_size_bounded(reader, genome_size, max_line_length)
+}
+
+/// Same as `compute_graph_stats`, additionally filling `ng50`/`lg50` when a
+/// genome size is given.
+pub fn compute_graph_stats_with_genome_size<R: BufRead>(
+    reader: R,
+    genome_size: Option<u64>,
+) -> Result<GraphStats, GfaError> {
+    compute_graph_stats_with_genome_size_bounded(reader, genome_size, None)
+}
+
+/// `compute_graph_stats_with_genome_size` with the `max_line_length` check of
+/// `compute_basic_stats_bounded`.
+pub fn compute_graph_stats_with_genome_size_bounded<R: BufRead>(
+    mut reader: R,
+    genome_size: Option<u64>,
+    max_line_length: Option<usize>,
+) -> Result<GraphStats, GfaError> {
+    skip_utf8_bom(&mut reader)?;
+
     let mut basic = BasicStats::default();
-    let mut node_lengths: Vec<u32> = Vec::new();
-    let mut degrees: HashMap<String, NodeDegree> = HashMap::new();
+    let mut node_lengths: Vec<u64> = Vec::new();
+    let mut degrees = NodeTable::default();
+    let mut path_steps: HashMap<u64, u64> = HashMap::new();
+    // distinct (from id, from forward, to id, to forward) links, and the
+    // ones seen more than once
+    let mut seen_links: HashSet<(u32, bool, u32, bool)> = HashSet::new();
+    let mut repeated_links: HashSet<(u32, bool, u32, bool)> = HashSet::new();
+    let mut self_loop_count: u64 = 0;
+    let mut duplicate_segment_count: u64 = 0;
+    let mut paths_with_repeats: u64 = 0;
+    let mut overlap_violations: Vec<OverlapViolation> = Vec::new();
+    // overlapping links seen before both of their S lines: (from, to, link)
+    let mut pending_overlaps: Vec<(u32, u32, OverlapViolation)> = Vec::new();
+    let mut buf = String::new();
+    let mut line_number: u64 = 0;
 
-    for line_result in reader.lines() {
-        let line = line_result?;
+    loop {
+        buf.clear();
+        line_number += 1;
+        let bytes_read = read_bounded_line(&mut reader, &mut buf, max_line_length, line_number)?;
+        if bytes_read == 0 {
+            break;
+        }
+
+        let line = buf.trim_end_matches('\n');
         let trimmed = line.trim();
         if trimmed.is_empty() {
             continue;
@@ -300,10 +1254,10 @@ pub fn compute_graph_stats<R: BufRead>(reader: R) -> Result<GraphStats, GfaError
                 let sid = fields.next();
                 let seq = fields
                     .next()
-                    .ok_or_else(|| GfaError::MalformedLine(line.to_string()))?;
+                    .ok_or_else(|| field_error(trimmed, 2, "missing sequence"))?;
 
-                if seq != "*" {
-                    let len = seq.len() as u64;
+                let seg_len = segment_length(seq, fields, trimmed)?;
+                if let Some(len) = seg_len {
                     basic.total_bp += len;
                     if len < basic.min_node_len {
                         basic.min_node_len = len;
@@ -313,21 +1267,24 @@ pub fn compute_graph_stats<R: BufRead>(reader: R) -> Result<GraphStats, GfaError
                     }
 
                     // Remember lengths for N50
-                    node_lengths.push(len as u32);
-
-                    // GC / N counting
-                    for b in seq.as_bytes() {
-                        match b {
-                            b'G' | b'g' | b'C' | b'c' => basic.gc_bases += 1,
-                            b'N' | b'n' => basic.n_bases += 1,
-                            _ => {}
-                        }
-                    }
+                    node_lengths.push(len);
+                }
+
+                if seq != "*" {
+                    // GC / N / soft-mask counting
+                    let mut composition = SequenceStats::default();
+                    composition.observe(seq.as_bytes());
+                    basic.add_composition(&composition);
                 }
 
                 // ensure node has degree entry so we count nodes with 0-degree too
                 if let Some(id) = sid {
-                    degrees.entry(id.to_string()).or_default();
+                    let entry = degrees.node(id);
+                    if entry.defined {
+                        duplicate_segment_count += 1;
+                    }
+                    entry.defined = true;
+                    entry.length = seg_len;
                 }
             }
             'L' => {
@@ -336,21 +1293,115 @@ pub fn compute_graph_stats<R: BufRead>(reader: R) -> Result<GraphStats, GfaError
                 let mut fields = trimmed.split('\t');
                 let _l = fields.next();
                 let from = fields.next();
-                let _from_orient = fields.next();
+                let from_orient = fields.next();
                 let to = fields.next();
-                let _to_orient = fields.next();
+                let to_orient = fields.next();
+                let overlap = fields.next();
+
+                if let (Some(from_id), Some(to_id), Some(overlap)) = (from, to, overlap) {
+                    let cigar = Cigar::parse(overlap)
+                        .map_err(|e| field_error(trimmed, 5, e.to_string()))?;
+                    let (from_overlap_bp, to_overlap_bp) =
+                        (cigar.reference_length(), cigar.query_length());
+                    if from_overlap_bp > 0 || to_overlap_bp > 0 {
+                        let mut link = OverlapViolation {
+                            from: from_id.to_string(),
+                            from_orient: from_orient.unwrap_or_default().to_string(),
+                            to: to_id.to_string(),
+                            to_orient: to_orient.unwrap_or_default().to_string(),
+                            overlap: overlap.to_string(),
+                            from_overlap_bp,
+                            to_overlap_bp,
+                            from_len: 0,
+                            to_len: 0,
+                        };
+                        let (from_key, to_key) = (degrees.id(from_id), degrees.id(to_id));
+                        let from_len = degrees.nodes[from_key as usize].length;
+                        let to_len = degrees.nodes[to_key as usize].length;
+                        match (from_len, to_len) {
+                            (Some(from_len), Some(to_len)) => {
+                                link.from_len = from_len;
+                                link.to_len = to_len;
+                                if link.is_violated() {
+                                    overlap_violations.push(link);
+                                }
+                            }
+                            _ => pending_overlaps.push((from_key, to_key, link)),
+                        }
+                    }
+                }
+
+                if let (Some(from_id), Some(to_id)) = (from, to) {
+                    if from_id == to_id {
+                        self_loop_count += 1;
+                    }
+                    let key = (
+                        degrees.id(from_id),
+                        from_orient != Some("-"),
+                        degrees.id(to_id),
+                        to_orient != Some("-"),
+                    );
+                    if !seen_links.insert(key) {
+                        repeated_links.insert(key);
+                    }
+                }
 
+                // leaving `from` forward uses its + end, entering `to`
+                // forward uses its - end; reverse orientations swap them
                 if let Some(from_id) = from {
-                    let entry = degrees.entry(from_id.to_string()).or_default();
+                    let entry = degrees.node(from_id);
                     entry.outdegree += 1;
+                    entry.link_orients |= orient_bit(from_orient);
+                    if from_orient == Some("-") {
+                        entry.minus_side += 1;
+                    } else {
+                        entry.plus_side += 1;
+                    }
                 }
                 if let Some(to_id) = to {
-                    let entry = degrees.entry(to_id.to_string()).or_default();
+                    let entry = degrees.node(to_id);
                     entry.indegree += 1;
+                    entry.link_orients |= orient_bit(to_orient);
+                    if to_orient == Some("-") {
+                        entry.plus_side += 1;
+                    } else {
+                        entry.minus_side += 1;
+                    }
                 }
             }
             'P' => {
+                // P <name> <seg+,seg-,...> <overlaps>
                 basic.path_count += 1;
+                let path_ordinal = basic.path_count;
+                let mut steps: u64 = 0;
+                let mut repeats = false;
+
+                let segments = trimmed.split('\t').nth(2).unwrap_or_default();
+                for step in segments.split(',').filter(|step| !step.is_empty()) {
+                    steps += 1;
+                    let (id, orient) = match step.strip_suffix(['+', '-']) {
+                        Some(id) => (id, Some(&step[id.len()..])),
+                        None => (step, None),
+                    };
+                    let entry = degrees.node(id);
+                    entry.depth += 1;
+                    entry.path_orients |= orient_bit(orient);
+                    if entry.last_path != path_ordinal {
+                        entry.last_path = path_ordinal;
+                        entry.paths += 1;
+                    } else {
+                        // already visited earlier in this same path
+                        repeats = true;
+                    }
+                }
+                if repeats {
+                    paths_with_repeats += 1;
+                }
+                *path_steps.entry(steps).or_insert(0) += 1;
+            }
+            'C' => {
+                check_containment_line(trimmed)?;
+                basic.containment_count += 1;
             }
             _ => {
                 basic.other_records += 1;
@@ -358,56 +1409,1162 @@ pub fn compute_graph_stats<R: BufRead>(reader: R) -> Result<GraphStats, GfaError
         }
     }
 
+    // links read before their segments; unknown lengths can't be checked
+    for (from_key, to_key, mut link) in pending_overlaps {
+        let from_len = degrees.nodes[from_key as usize].length;
+        let to_len = degrees.nodes[to_key as usize].length;
+        if let (Some(from_len), Some(to_len)) = (from_len, to_len) {
+            link.from_len = from_len;
+            link.to_len = to_len;
+            if link.is_violated() {
+                overlap_violations.push(link);
+            }
+        }
+    }
+
     // normalize basic stats (min length when no nodes)
     basic = basic.normalized();
 
-    // ---- N50 / L50 ----
-    let (n50, l50) = compute_n50(&node_lengths, basic.total_bp);
+    // ---- N50 / L50, N90 / L90, NG50 / LG50 ----
+    node_lengths.sort_unstable_by(|a, b| b.cmp(a)); // descending
+    let (n50, l50) = nx_sorted(&node_lengths, basic.total_bp, 0.5).unwrap_or((0, 0));
+    let (n90, l90) = nx_sorted(&node_lengths, basic.total_bp, 0.9).unwrap_or((0, 0));
+    // None both when not requested and when the segments never cover half
+    // the genome, so an uncovered NG50 isn't mistaken for a real 0.
+    let ng = genome_size.and_then(|size| nx_sorted(&node_lengths, size, 0.5));
+    let (ng50, lg50) = (ng.map(|(n, _)| n), ng.map(|(_, l)| l));
 
     // ---- Degree histogram & branching ----
     let mut hist: HashMap<u32, u64> = HashMap::new();
     let mut branching_nodes: u64 = 0;
-
-    for deg in degrees.values() {
+    let mut tip_count: u64 = 0;
+    let mut isolated_node_count: u64 = 0;
+    let mut plus_hist: HashMap<u32, u64> = HashMap::new();
+    let mut minus_hist: HashMap<u32, u64> = HashMap::new();
+    let mut two_sided_nodes: u64 = 0;
+    let mut plus_side_only_nodes: u64 = 0;
+    let mut minus_side_only_nodes: u64 = 0;
+
+    for deg in &degrees.nodes {
         let total_deg = deg.indegree + deg.outdegree;
+        // names only ever seen in P lines aren't graph nodes
+        if !deg.defined && total_deg == 0 {
+            continue;
+        }
         *hist.entry(total_deg).or_insert(0) += 1;
-        if total_deg > 2 {
-            branching_nodes += 1;
+        match total_deg {
+            0 => isolated_node_count += 1,
+            1 => tip_count += 1,
+            d if d > 2 => branching_nodes += 1,
+            _ => {}
+        }
+        *plus_hist.entry(deg.plus_side).or_insert(0) += 1;
+        *minus_hist.entry(deg.minus_side).or_insert(0) += 1;
+        match (deg.plus_side > 0, deg.minus_side > 0) {
+            (true, true) => two_sided_nodes += 1,
+            (true, false) => plus_side_only_nodes += 1,
+            (false, true) => minus_side_only_nodes += 1,
+            (false, false) => {}
         }
     }
 
     let mut degree_histogram: Vec<(u32, u64)> = hist.into_iter().collect();
     degree_histogram.sort_by_key(|(d, _)| *d);
+    let mut plus_side_degree_histogram: Vec<(u32, u64)> = plus_hist.into_iter().collect();
+    plus_side_degree_histogram.sort_by_key(|(d, _)| *d);
+    let mut minus_side_degree_histogram: Vec<(u32, u64)> = minus_hist.into_iter().collect();
+    minus_side_degree_histogram.sort_by_key(|(d, _)| *d);
+
+    // ---- Node depth (path coverage) ----
+    let mut depth_hist: HashMap<u32, u64> = HashMap::new();
+    let mut unknown_path_steps: u64 = 0;
+    let mut pangenome_classes = PangenomeClasses::default();
+
+    for deg in &degrees.nodes {
+        if !deg.defined {
+            unknown_path_steps += deg.depth as u64;
+            continue;
+        }
+        *depth_hist.entry(deg.depth).or_insert(0) += 1;
+        let paths = deg.paths as u64;
+        if paths == 0 {
+            // not on any path
+        } else if paths == basic.path_count {
+            pangenome_classes.core.add(deg.length.unwrap_or(0));
+        } else if paths == 1 {
+            pangenome_classes.cloud.add(deg.length.unwrap_or(0));
+        } else {
+            pangenome_classes.shell.add(deg.length.unwrap_or(0));
+        }
+    }
+
+    let mut orientation_mismatches: Vec<String> = degrees
+        .ids
+        .iter()
+        .filter(|(_, &id)| {
+            let deg = &degrees.nodes[id as usize];
+            let one_way = |bits: u8| bits == ORIENT_FORWARD || bits == ORIENT_REVERSE;
+            one_way(deg.link_orients)
+                && one_way(deg.path_orients)
+                && deg.link_orients != deg.path_orients
+        })
+        .map(|(name, _)| name.clone())
+        .collect();
+    orientation_mismatches.sort_unstable();
+
+    let mut node_depth_histogram: Vec<(u32, u64)> = depth_hist.into_iter().collect();
+    node_depth_histogram.sort_by_key(|(d, _)| *d);
+
+    // ---- Anomalies ----
+    let multi_edge_count = repeated_links.len() as u64;
+
+    let mut path_step_histogram: Vec<(u64, u64)> = path_steps.into_iter().collect();
+    path_step_histogram.sort_by_key(|(steps, _)| *steps);
 
     Ok(GraphStats {
         basic,
         n50,
         l50,
+        n90,
+        l90,
+        ng50,
+        lg50,
         degree_histogram,
         branching_nodes,
+        tip_count,
+        isolated_node_count,
+        plus_side_degree_histogram,
+        minus_side_degree_histogram,
+        two_sided_nodes,
+        plus_side_only_nodes,
+        minus_side_only_nodes,
+        path_step_histogram,
+        self_loop_count,
+        multi_edge_count,
+        duplicate_segment_count,
+        overlap_violations,
+        node_depth_histogram,
+        core_segments: pangenome_classes.core.segments,
+        private_segments: pangenome_classes.cloud.segments,
+        unknown_path_steps,
+        orientation_mismatches,
+        paths_with_repeats,
+        pangenome_classes,
     })
 }
 
-fn compute_n50(lengths: &[u32], total_bp: u64) -> (u64, u64) {
-    if lengths.is_empty() || total_bp == 0 {
-        return (0, 0);
-    }
-    let mut lens: Vec<u32> = lengths.to_vec();
+/// NG50 / LG50 of `lengths` measured against `genome_size` instead of the
+/// sum of lengths. `None` if the lengths never cover half the genome size.
+pub fn assembly_metrics_ng(lengths: &[u64], genome_size: u64) -> Option<(u64, u64)> {
+    let mut lens: Vec<u64> = lengths.to_vec();
     lens.sort_unstable_by(|a, b| b.cmp(a)); // descending
+    nx_sorted(&lens, genome_size, 0.5)
+}
 
-    let half = total_bp / 2;
+/// Nx / Lx of `lengths` against `total`: the length of the segment at which
+/// the cumulative length (longest first) reaches `fraction * total`, and
+/// how many segments it took. `compute_nx(lengths, total_bp, 0.5)` is N50.
+/// Returns `(0, 0)` if the lengths never reach the threshold.
+pub fn compute_nx(lengths: &[u64], total: u64, fraction: f64) -> (u64, u64) {
+    let mut lens: Vec<u64> = lengths.to_vec();
+    lens.sort_unstable_by(|a, b| b.cmp(a)); // descending
+    nx_sorted(&lens, total, fraction).unwrap_or((0, 0))
+}
+
+/// `compute_nx` over lengths already sorted in descending order; `None` if
+/// the threshold is never reached.
+fn nx_sorted(sorted_desc: &[u64], total: u64, fraction: f64) -> Option<(u64, u64)> {
+    if sorted_desc.is_empty() || total == 0 {
+        return None;
+    }
+
+    // Compare in f64 without truncating the target, so e.g. N90 of a 15 bp
+    // total needs 13.5 bp covered, not 13.
+    let threshold = total as f64 * fraction;
     let mut cum: u64 = 0;
-    let mut l50: u64 = 0;
-    let mut n50: u64 = 0;
-
-    for (i, len) in lens.iter().enumerate() {
-        cum += *len as u64;
-        if cum >= half {
-            n50 = *len as u64;
-            l50 = (i + 1) as u64;
+
+    for (i, len) in sorted_desc.iter().enumerate() {
+        cum += *len;
+        if cum as f64 >= threshold {
+            return Some((*len, (i + 1) as u64));
+        }
+    }
+
+    None
+}
+
+// ================== Segment length export ==================
+
+/// Stream every segment length (one integer per line) to `out`.
+///
+/// Segments with a `*` sequence use their `LN:i:` tag, and are skipped if
+/// they have none, matching `BasicStats`. When `sorted` is set the lengths
+/// are buffered and written in descending order; otherwise they are written
+/// in file order without buffering. Returns the number of lengths written.
+pub fn write_segment_lengths<R: BufRead, W: Write>(
+    mut reader: R,
+    out: &mut W,
+    sorted: bool,
+) -> Result<u64, GfaError> {
+    skip_utf8_bom(&mut reader)?;
+
+    let mut buffered: Vec<u64> = Vec::new();
+    let mut written: u64 = 0;
+
+    for line_result in reader.lines() {
+        let line = line_result?;
+        let trimmed = line.trim();
+        if !trimmed.starts_with('S') {
+            continue;
+        }
+
+        let mut fields = trimmed.split('\t').skip(2);
+        let seq = fields
+            .next()
+            .ok_or_else(|| field_error(trimmed, 2, "missing sequence"))?;
+        let Some(len) = segment_length(seq, fields, trimmed)? else {
+            continue;
+        };
+
+        if sorted {
+            buffered.push(len);
+        } else {
+            writeln!(out, "{len}")?;
+        }
+        written += 1;
+    }
+
+    if sorted {
+        buffered.sort_unstable_by(|a, b| b.cmp(a));
+        for len in &buffered {
+            writeln!(out, "{len}")?;
+        }
+    }
+
+    out.flush()?;
+    Ok(written)
+}
+
+// ================== Split by record type ==================
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct SplitCounts {
+    pub segments: u64,
+    pub links: u64,
+    pub paths: u64,
+    pub other: u64,
+}
+
+/// Stream a GFA and append each record to a per-type file in `outdir`.
+///
+/// `S`, `L` and `P` lines go to `segments.gfa`, `links.gfa` and `paths.gfa`;
+/// any other non-empty line (headers, walks, comments, ...) goes to
+/// `other.gfa`, which is only created if such a line is seen. Lines are
+/// written unchanged and nothing is buffered beyond the writers.
+pub fn split_by_record_type<R: BufRead, P: AsRef<Path>>(
+    mut reader: R,
+    outdir: P,
+) -> Result<SplitCounts, GfaError> {
+    skip_utf8_bom(&mut reader)?;
+
+    let outdir = outdir.as_ref();
+    std::fs::create_dir_all(outdir)?;
+
+    let create = |name: &str| -> Result<BufWriter<File>, GfaError> {
+        Ok(BufWriter::new(File::create(outdir.join(name))?))
+    };
+
+    let mut segments = create("segments.gfa")?;
+    let mut links = create("links.gfa")?;
+    let mut paths = create("paths.gfa")?;
+    let mut other: Option<BufWriter<File>> = None;
+    let mut counts = SplitCounts::default();
+
+    let mut buf = String::new();
+    loop {
+        buf.clear();
+        if reader.read_line(&mut buf)? == 0 {
             break;
         }
+        if buf.trim().is_empty() {
+            continue;
+        }
+        // keep the line ending as read (e.g. CRLF); only a final line
+        // without one gets a '\n' so the outputs still end in a newline
+        if !buf.ends_with('\n') {
+            buf.push('\n');
+        }
+
+        let writer = match buf.trim_start().split('\t').next() {
+            Some("S") => {
+                counts.segments += 1;
+                &mut segments
+            }
+            Some("L") => {
+                counts.links += 1;
+                &mut links
+            }
+            Some("P") => {
+                counts.paths += 1;
+                &mut paths
+            }
+            _ => {
+                counts.other += 1;
+                match other.as_mut() {
+                    Some(w) => w,
+                    None => other.insert(create("other.gfa")?),
+                }
+            }
+        };
+        writer.write_all(buf.as_bytes())?;
+    }
+
+    segments.flush()?;
+    links.flush()?;
+    paths.flush()?;
+    if let Some(w) = other.as_mut() {
+        w.flush()?;
+    }
+
+    Ok(counts)
+}
+
+// ================== Graph fingerprint ==================
+
+const FINGERPRINT_PREFIX: &str = "pgfp1";
+const FNV_OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+const FNV_PRIME: u64 = 0x0000_0100_0000_01b3;
+
+/// Compact signature of a GFA: content hash plus S/L/P counts and total bp.
+///
+/// The hash is 64-bit FNV-1a over every non-empty line with surrounding
+/// whitespace trimmed, so it is stable across platforms and releases and
+/// insensitive to line endings.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Fingerprint {
+    pub hash: u64,
+    pub segments: u64,
+    pub links: u64,
+    pub paths: u64,
+    pub total_bp: u64,
+}
+
+impl std::fmt::Display for Fingerprint {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{FINGERPRINT_PREFIX}:{:016x}:{}:{}:{}:{}",
+            self.hash, self.segments, self.links, self.paths, self.total_bp
+        )
+    }
+}
+
+pub fn compute_fingerprint<R: BufRead>(mut reader: R) -> Result<Fingerprint, GfaError> {
+    skip_utf8_bom(&mut reader)?;
+
+    let mut stats = BasicStats::default();
+    let mut hash = FNV_OFFSET_BASIS;
+
+    for line_result in reader.lines() {
+        let line = line_result?;
+        let trimmed = line.trim();
+        if trimmed.is_empty() {
+            continue;
+        }
+
+        hash = fnv1a(fnv1a(hash, trimmed.as_bytes()), b"\n");
+
+        process_line(&mut stats, trimmed)?;
+    }
+
+    Ok(Fingerprint {
+        hash,
+        segments: stats.node_count,
+        links: stats.edge_count,
+        paths: stats.path_count,
+        total_bp: stats.total_bp,
+    })
+}
+
+/// Feed `bytes` into a running 64-bit FNV-1a `hash`.
+fn fnv1a(mut hash: u64, bytes: &[u8]) -> u64 {
+    for b in bytes {
+        hash ^= *b as u64;
+        hash = hash.wrapping_mul(FNV_PRIME);
+    }
+    hash
+}
+
+pub fn compute_fingerprint_from_path<P: AsRef<Path>>(path: P) -> Result<Fingerprint, GfaError> {
+    let reader = open_gfa_reader(&path)?;
+    compute_fingerprint(reader)
+}
+
+// ================== TSV output ==================
+
+/// Render a serialized stats value as `metric\tvalue` rows.
+///
+/// Nested objects are flattened into dotted metric names (`basic.total_bp`).
+/// Arrays of `(key, value)` pairs, such as the histograms, become one
+/// `metric.key` row per pair; other arrays use the element index as the key.
+/// `null` values (unset optional metrics) are omitted.
+pub fn stats_to_tsv(value: &serde_json::Value) -> String {
+    let mut out = String::from("metric\tvalue\n");
+    flatten_tsv_rows("", value, &mut out);
+    out
+}
+
+fn flatten_tsv_rows(prefix: &str, value: &serde_json::Value, out: &mut String) {
+    use serde_json::Value;
+
+    let join = |key: &str| {
+        if prefix.is_empty() {
+            key.to_string()
+        } else {
+            format!("{prefix}.{key}")
+        }
+    };
+
+    match value {
+        Value::Null => {}
+        Value::Object(map) => {
+            for (key, v) in map {
+                flatten_tsv_rows(&join(key), v, out);
+            }
+        }
+        Value::Array(items) => {
+            for (i, item) in items.iter().enumerate() {
+                match item.as_array().map(Vec::as_slice) {
+                    Some([key, v]) if !key.is_array() && !key.is_object() => {
+                        let key = key.as_str().map_or_else(|| key.to_string(), str::to_string);
+                        flatten_tsv_rows(&join(&key), v, out);
+                    }
+                    _ => flatten_tsv_rows(&join(&i.to_string()), item, out),
+                }
+            }
+        }
+        Value::String(s) => {
+            out.push_str(&format!("{prefix}\t{s}\n"));
+        }
+        other => {
+            out.push_str(&format!("{prefix}\t{other}\n"));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graph_stats(gfa: &str) -> GraphStats {
+        compute_graph_stats(gfa.as_bytes()).unwrap()
+    }
+
+    /// Fresh scratch directory under the system temp dir.
+    fn temp_dir(name: &str) -> std::path::PathBuf {
+        let dir = std::env::temp_dir().join(format!("pgtools-test-{}-{name}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn nx_does_not_round_the_target_down() {
+        // total 15: N50 needs 7.5 bp, N90 needs 13.5 bp (10 + 3 = 13 is short)
+        assert_eq!(compute_nx(&[3, 10, 2], 15, 0.5), (10, 1));
+        assert_eq!(compute_nx(&[3, 10, 2], 15, 0.9), (2, 3));
+        assert_eq!(compute_nx(&[], 15, 0.5), (0, 0));
+    }
+
+    #[test]
+    fn ng50_uses_genome_size_instead_of_total() {
+        // total 15 but genome 30: NG50 needs 15 bp, reached only at the 2
+        assert_eq!(assembly_metrics_ng(&[10, 3, 2], 30), Some((2, 3)));
+        assert_eq!(compute_nx(&[10, 3, 2], 15, 0.5), (10, 1));
+
+        let gfa = "S\ta\tAAAAAAAAAA\nS\tb\tCCC\nS\tc\tGG\n";
+        let stats = compute_graph_stats_with_genome_size(gfa.as_bytes(), Some(30)).unwrap();
+        assert_eq!((stats.ng50, stats.lg50), (Some(2), Some(3)));
+        assert_ne!(stats.ng50, Some(stats.n50));
+    }
+
+    #[test]
+    fn ng50_is_none_when_genome_is_not_half_covered() {
+        assert_eq!(assembly_metrics_ng(&[10, 3, 2], 100), None);
+
+        let gfa = "S\ta\tAAAAAAAAAA\n";
+        let stats = compute_graph_stats_with_genome_size(gfa.as_bytes(), Some(100)).unwrap();
+        assert_eq!((stats.ng50, stats.lg50), (None, None));
+
+        let stats = compute_graph_stats(gfa.as_bytes()).unwrap();
+        assert_eq!((stats.ng50, stats.lg50), (None, None));
+    }
+
+    #[test]
+    fn path_steps_on_undefined_segments_are_not_nodes() {
+        let stats = graph_stats("S\ta\tACGT\nP\tp1\ta+,ghost+\t*\n");
+        assert_eq!(stats.isolated_node_count, 1);
+        assert_eq!(stats.degree_histogram, vec![(0, 1)]);
+        assert_eq!(stats.node_depth_histogram, vec![(1, 1)]);
+        // with a single path its segments are core, not private
+        assert_eq!(stats.core_segments, 1);
+        assert_eq!(stats.private_segments, 0);
+        assert_eq!(stats.unknown_path_steps, 1);
+    }
+
+    #[test]
+    fn pangenome_classes_split_core_shell_cloud() {
+        let gfa = "S\tcore\tAAAA\nS\tshell\tCC\nS\tcloud\tG\nS\tunused\tTTTTT\n\
+                   P\tp1\tcore+,shell+,cloud+\t*\n\
+                   P\tp2\tcore+,shell+\t*\n\
+                   P\tp3\tcore+\t*\n";
+        let stats = graph_stats(gfa);
+        let classes = &stats.pangenome_classes;
+        assert_eq!((classes.core.segments, classes.core.bp), (1, 4));
+        assert_eq!((classes.shell.segments, classes.shell.bp), (1, 2));
+        assert_eq!((classes.cloud.segments, classes.cloud.bp), (1, 1));
+        assert_eq!((stats.core_segments, stats.private_segments), (1, 1));
+    }
+
+    #[test]
+    fn paths_revisiting_a_segment_are_counted_once() {
+        let gfa = "S\ts1\tA\nS\ts2\tC\nS\ts3\tG\n\
+                   P\tp1\ts1+,s2+,s3+,s2-,s2+\t*\n\
+                   P\tp2\ts1+,s2+,s3+\t*\n";
+        let stats = graph_stats(gfa);
+        assert_eq!(stats.paths_with_repeats, 1);
+        assert_eq!(stats.node_depth_histogram, vec![(2, 2), (4, 1)]);
+    }
+
+    #[test]
+    fn side_degrees_count_every_link_on_an_end() {
+        // (+, -) degrees: a (5, 1), b (1, 2), c (2, 1)
+        let stats = graph_stats(
+            "S\ta\tA\nS\tb\tC\nS\tc\tG\n\
+             L\ta\t+\tb\t+\t*\nL\ta\t+\tb\t-\t*\nL\ta\t+\tc\t+\t*\n\
+             L\ta\t+\tc\t-\t*\nL\tb\t-\ta\t-\t*\nL\tc\t+\ta\t+\t*\n",
+        );
+        assert_eq!(
+            stats.plus_side_degree_histogram,
+            vec![(1, 1), (2, 1), (5, 1)]
+        );
+        assert_eq!(stats.minus_side_degree_histogram, vec![(1, 2), (2, 1)]);
+        // the total degree of a is unchanged by sides
+        assert_eq!(stats.degree_histogram.last(), Some(&(6, 1)));
+    }
+
+    #[test]
+    fn link_orientations_pick_the_segment_end() {
+        // b is entered on its 5' end and left from its 3' end
+        let gfa = "S\ta\tA\nS\tb\tC\nS\tc\tG\nS\td\tT\n\
+                   L\ta\t+\tb\t+\t*\nL\tb\t+\tc\t+\t*\nL\td\t-\tc\t-\t*\n";
+        let stats = graph_stats(gfa);
+        // (+, -) degrees: a (1, 0), b (1, 1), c (1, 1) via the d- -> c- link, d (0, 1)
+        assert_eq!(stats.plus_side_degree_histogram, vec![(0, 1), (1, 3)]);
+        assert_eq!(stats.minus_side_degree_histogram, vec![(0, 1), (1, 3)]);
+        assert_eq!(stats.two_sided_nodes, 2);
+        assert_eq!(stats.plus_side_only_nodes, 1);
+        assert_eq!(stats.minus_side_only_nodes, 1);
+        // the plain degree histogram is unchanged by sides
+        assert_eq!(stats.degree_histogram, vec![(1, 2), (2, 2)]);
+    }
+
+    #[test]
+    fn over_long_lines_are_rejected_by_every_entry_point() {
+        let gfa = "S\ta\tACGT\nS\tb\tACGTACGTACGTACGTACGT\n";
+        let too_long = |err: GfaError| {
+            matches!(
+                err,
+                GfaError::LineTooLong {
+                    line_number: 2,
+                    max_length: 10
+                }
+            )
+        };
+
+        let err = compute_basic_stats_bounded(gfa.as_bytes(), Some(10)).unwrap_err();
+        assert!(too_long(err));
+        let err = compute_basic_stats_overlap_adjusted_bounded(gfa.as_bytes(), Some(10));
+        assert!(too_long(err.unwrap_err()));
+        let err = compute_graph_stats_with_genome_size_bounded(gfa.as_bytes(), None, Some(10));
+        assert!(too_long(err.unwrap_err()));
+
+        let dir = temp_dir("max-line-length");
+        let input = dir.join("in.gfa");
+        std::fs::write(&input, gfa).unwrap();
+        let err = compute_basic_stats_resumable_bounded(&input, dir.join("ckpt"), Some(10));
+        assert!(too_long(err.unwrap_err()));
+
+        // lines within the limit still go through
+        let stats = compute_basic_stats_bounded(gfa.as_bytes(), Some(30)).unwrap();
+        assert_eq!(stats.node_count, 2);
+        std::fs::remove_dir_all(dir).unwrap();
     }
 
-    (n50, l50)
+    /// Run `input` with a line limit that the last line exceeds, leaving the
+    /// checkpoint of a genuinely interrupted run behind.
+    fn interrupted_run(input: &Path, checkpoint: &Path, max_line_length: usize) {
+        let err = compute_basic_stats_resumable_every(input, checkpoint, 1, Some(max_line_length))
+            .unwrap_err();
+        assert!(matches!(err, GfaError::LineTooLong { .. }), "{err}");
+    }
+
+    #[test]
+    fn resumable_stats_continue_from_a_mid_file_checkpoint() {
+        let gfa = "H\tVN:Z:1.0\n# c\nS\ta\tACgt\nS\tb\tGN\nL\ta\t+\tb\t+\t0M\nS\tc\tTTTTTTTTTTTTTTTTTTTTTTTTTT\n";
+        let head = &gfa[..gfa.rfind("S\tc").unwrap()];
+        let dir = temp_dir("resume-mid-file");
+        let input = dir.join("in.gfa");
+        let checkpoint = dir.join("ckpt.json");
+        std::fs::write(&input, gfa).unwrap();
+
+        interrupted_run(&input, &checkpoint, 20);
+        let cp = read_checkpoint(&checkpoint).unwrap().unwrap();
+        assert_eq!(cp.offset, head.len() as u64);
+        assert_eq!(cp.line_number, head.lines().count() as u64);
+
+        let resumed = compute_basic_stats_resumable_every(&input, &checkpoint, 1, None).unwrap();
+        let fresh = compute_basic_stats(gfa.as_bytes()).unwrap();
+        assert_eq!(resumed, fresh);
+        assert!(!checkpoint.exists());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn resumable_stats_ignore_a_checkpoint_for_an_edited_file() {
+        let dir = temp_dir("resume-stale");
+        let input = dir.join("in.gfa");
+        let checkpoint = dir.join("ckpt.json");
+        std::fs::write(&input, "S\ta\tACGT\nS\tb\tGGGGGGGGGGGGGGGGGGGG\n").unwrap();
+        interrupted_run(&input, &checkpoint, 16);
+        assert!(checkpoint.exists());
+
+        // same length, different content
+        let edited = "S\ta\tAAAAAAAAAAAAAAAAAAAA\nS\tb\tCCCC\n";
+        std::fs::write(&input, edited).unwrap();
+        assert_eq!(std::fs::metadata(&input).unwrap().len(), 34);
+
+        let stats = compute_basic_stats_resumable_every(&input, &checkpoint, 1, None).unwrap();
+        assert_eq!(stats, compute_basic_stats(edited.as_bytes()).unwrap());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn unreadable_checkpoints_are_checkpoint_errors() {
+        let dir = temp_dir("bad-checkpoint");
+        let checkpoint = dir.join("ckpt.json");
+        std::fs::write(&checkpoint, "not json").unwrap();
+        let err = read_checkpoint(&checkpoint).unwrap_err();
+        assert!(matches!(err, GfaError::Checkpoint(_)));
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn watch_recomputes_only_when_the_signature_changes() {
+        let mut signatures = [1, 1, 2, 2, 2, 3].into_iter();
+        let mut polls_left = signatures.len();
+        let mut runs = 0;
+        let reported = watch_for_changes(
+            || Ok::<_, GfaError>(signatures.next().unwrap()),
+            || {
+                polls_left -= 1;
+                polls_left > 0
+            },
+            || {
+                runs += 1;
+                Ok(())
+            },
+            |err| panic!("unexpected error: {err}"),
+        )
+        .unwrap();
+        assert_eq!((reported, runs), (3, 3));
+    }
+
+    #[test]
+    fn watch_skips_an_untouched_file_and_rereads_an_edited_one() {
+        let dir = temp_dir("watch");
+        let input = dir.join("in.gfa");
+        std::fs::write(&input, "S\ta\tACGT\n").unwrap();
+
+        let mut node_counts = Vec::new();
+        let mut polls = 0;
+        watch_for_changes(
+            || file_signature(&input),
+            || {
+                polls += 1;
+                if polls == 2 {
+                    std::fs::write(&input, "S\ta\tACGT\nS\tb\tGG\n").unwrap();
+                }
+                polls < 4
+            },
+            || {
+                node_counts.push(compute_basic_stats_from_path(&input)?.node_count);
+                Ok(())
+            },
+            |err| panic!("unexpected error: {err}"),
+        )
+        .unwrap();
+        // polls 1-2 see the original file, 3-4 the edited one
+        assert_eq!(node_counts, vec![1, 2]);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn watch_keeps_polling_past_a_half_written_line() {
+        let dir = temp_dir("watch-partial");
+        let input = dir.join("in.gfa");
+        std::fs::write(&input, "S\ta\tACGT\nS\tb").unwrap();
+
+        let mut node_counts = Vec::new();
+        let mut errors = Vec::new();
+        let mut polls = 0;
+        let runs = watch_for_changes(
+            || file_signature(&input),
+            || {
+                polls += 1;
+                if polls == 1 {
+                    // the writer finishes the S line before the next poll
+                    std::fs::write(&input, "S\ta\tACGT\nS\tb\tGG\n").unwrap();
+                }
+                polls < 3
+            },
+            || {
+                node_counts.push(compute_basic_stats_from_path(&input)?.node_count);
+                Ok(())
+            },
+            |err| errors.push(err),
+        )
+        .unwrap();
+
+        assert!(matches!(errors[..], [GfaError::MalformedField { .. }]));
+        assert_eq!(node_counts, vec![2]);
+        assert_eq!(runs, 1);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn summary_json_adds_mean_node_len_to_the_stored_fields() {
+        let stats =
+            compute_basic_stats("S\ta\tACGT\nS\tb\tGG\nL\ta\t+\tb\t+\t*\n".as_bytes()).unwrap();
+        let json = stats.summary_json();
+        assert_eq!(json["node_count"], 2);
+        assert_eq!(json["edge_count"], 1);
+        assert_eq!(json["total_bp"], 6);
+        assert_eq!(json["gc_bases"], 4);
+        assert_eq!(json["mean_node_len"], 3.0);
+
+        // embedded stats serialize through the same view
+        let graph = serde_json::to_value(graph_stats("S\ta\tACGT\nS\tb\tGG\n")).unwrap();
+        assert_eq!(graph["basic"]["mean_node_len"], 3.0);
+        let adjusted = compute_basic_stats_overlap_adjusted("S\ta\tACGT\n".as_bytes()).unwrap();
+
+        // written views keep the struct's field order, mean_node_len last
+        let text = serde_json::to_string(&stats.summary()).unwrap();
+        assert!(
+            text.starts_with("{\"total_lines\":3,\"node_count\":2,"),
+            "{text}"
+        );
+        assert!(text.ends_with(",\"mean_node_len\":3.0}"), "{text}");
+        let text = serde_json::to_string(&graph_stats("S\ta\tACGT\n")).unwrap();
+        assert!(text.starts_with("{\"basic\":{\"total_lines\":1,"), "{text}");
+        assert_eq!(
+            serde_json::to_value(adjusted).unwrap()["basic"]["mean_node_len"],
+            4.0
+        );
+    }
+
+    #[test]
+    fn a_leading_bom_is_ignored_everywhere() {
+        let plain = "S\ta\tACGT\nL\ta\t+\ta\t-\t*\n";
+        let bom = format!("\u{feff}{plain}");
+
+        let stats = compute_basic_stats(bom.as_bytes()).unwrap();
+        assert_eq!((stats.node_count, stats.other_records), (1, 0));
+        assert_eq!(graph_stats(&bom).basic.node_count, 1);
+
+        let mut lengths = Vec::new();
+        write_segment_lengths(bom.as_bytes(), &mut lengths, false).unwrap();
+        assert_eq!(lengths, b"4\n");
+
+        assert_eq!(
+            compute_fingerprint(bom.as_bytes()).unwrap(),
+            compute_fingerprint(plain.as_bytes()).unwrap()
+        );
+
+        let dir = temp_dir("bom-split");
+        let counts = split_by_record_type(bom.as_bytes(), &dir).unwrap();
+        assert_eq!((counts.segments, counts.other), (1, 0));
+        assert!(!dir.join("other.gfa").exists());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn containment_lines_are_counted() {
+        let gfa = "S\ta\tACGTACGT\nS\tb\tCGTA\nC\ta\t+\tb\t+\t1\t4M\n";
+        assert_eq!(
+            compute_basic_stats(gfa.as_bytes())
+                .unwrap()
+                .containment_count,
+            1
+        );
+        assert_eq!(graph_stats(gfa).basic.containment_count, 1);
+        assert_eq!(graph_stats(gfa).basic.other_records, 0);
+    }
+
+    #[test]
+    fn malformed_containment_lines_are_errors() {
+        let gfa = "S\ta\tACGTACGT\nC\ta\t+\tb\t+\n";
+        let err = compute_basic_stats(gfa.as_bytes()).unwrap_err();
+        assert!(matches!(err, GfaError::MalformedField { field: 5, .. }));
+        let err = compute_graph_stats(gfa.as_bytes()).unwrap_err();
+        assert!(matches!(err, GfaError::MalformedField { field: 5, .. }));
+    }
+
+    #[test]
+    fn repeated_segment_names_are_counted_as_duplicates() {
+        let stats = graph_stats("S\ts1\tACGT\nS\ts2\tGG\nS\ts1\tTTTT\n");
+        assert_eq!(stats.duplicate_segment_count, 1);
+        // every S line still counts toward the basic totals
+        assert_eq!(stats.basic.node_count, 3);
+    }
+
+    #[test]
+    fn cigar_parses_zero_star_and_multi_op_overlaps() {
+        let zero = Cigar::parse("0M").unwrap();
+        assert_eq!(zero.ops, vec![(0, CigarOp::Match)]);
+        assert_eq!(zero.reference_length(), 0);
+
+        let star = Cigar::parse("*").unwrap();
+        assert!(star.ops.is_empty());
+        assert_eq!((star.reference_length(), star.query_length()), (0, 0));
+
+        let multi = Cigar::parse("47M2D3M1I").unwrap();
+        assert_eq!(
+            multi.ops,
+            vec![
+                (47, CigarOp::Match),
+                (2, CigarOp::Deletion),
+                (3, CigarOp::Match),
+                (1, CigarOp::Insertion),
+            ]
+        );
+        assert_eq!(multi.reference_length(), 52);
+        assert_eq!(multi.query_length(), 51);
+        assert_eq!(multi.match_length(), 50);
+    }
+
+    #[test]
+    fn cigar_rejects_malformed_overlaps() {
+        for bad in ["", "M", "5", "5M3", "5Q", "-5M", "5M*", "99999999999M"] {
+            assert!(
+                matches!(Cigar::parse(bad), Err(GfaError::InvalidCigar(_))),
+                "{bad:?} should not parse"
+            );
+        }
+    }
+
+    #[test]
+    fn field_errors_put_the_caret_under_the_bad_field() {
+        // fields are numbered from 1 in the message
+        let rendered = render_field_error("S\tseg1\tACGT\tLN:i:x", 3, "bad tag");
+        assert_eq!(
+            rendered,
+            "Malformed GFA line (field 4: bad tag):\nS\tseg1\tACGT\tLN:i:x\n \t    \t    \t^^^^^^"
+        );
+
+        let rendered = render_field_error("L\tseg1\tx\tseg2\t+\t*", 2, "bad orientation");
+        assert_eq!(rendered.lines().last().unwrap(), " \t    \t^");
+
+        // a missing trailing field gets a single caret just past the end
+        let rendered = render_field_error("L\ta\t+\tb", 4, "missing to orientation");
+        let marker = rendered.lines().last().unwrap();
+        assert_eq!(marker, " \t \t \t \t^");
+    }
+
+    #[test]
+    fn malformed_lines_render_through_the_error_display() {
+        let err = compute_basic_stats("S\ta\t*\tLN:i:ten\n".as_bytes()).unwrap_err();
+        let text = err.to_string();
+        assert!(text.starts_with("Malformed GFA line (field 4: "), "{text}");
+        assert!(text.ends_with("\n \t \t \t^^^^^^^^"), "{text}");
+    }
+
+    #[test]
+    fn identical_graphs_share_a_fingerprint() {
+        let gfa = "H\tVN:Z:1.0\nS\ta\tACGT\nS\tb\tGG\nL\ta\t+\tb\t+\t*\nP\tp\ta+,b+\t*\n";
+        let first = compute_fingerprint(gfa.as_bytes()).unwrap();
+        let crlf = gfa.replace('\n', "\r\n");
+        assert_eq!(first, compute_fingerprint(gfa.as_bytes()).unwrap());
+        assert_eq!(first, compute_fingerprint(crlf.as_bytes()).unwrap());
+        assert_eq!(
+            first.to_string(),
+            format!("pgfp1:{:016x}:2:1:1:6", first.hash)
+        );
+    }
+
+    #[test]
+    fn editing_a_graph_changes_its_fingerprint() {
+        let gfa = "S\ta\tACGT\nS\tb\tGG\nL\ta\t+\tb\t+\t*\n";
+        let original = compute_fingerprint(gfa.as_bytes()).unwrap();
+
+        // same counts and total bp, one base changed
+        let edited = compute_fingerprint(gfa.replace("ACGT", "ACGA").as_bytes()).unwrap();
+        assert_ne!(original.hash, edited.hash);
+        assert_ne!(original.to_string(), edited.to_string());
+        assert_eq!(original.total_bp, edited.total_bp);
+    }
+
+    #[test]
+    fn segment_lengths_match_the_known_lengths() {
+        let gfa = "S\ta\tACGT\nS\tb\t*\tLN:i:12\nS\tc\tG\nS\td\t*\nL\ta\t+\tc\t+\t*\nS\te\tACGT\n";
+
+        let mut out = Vec::new();
+        assert_eq!(
+            write_segment_lengths(gfa.as_bytes(), &mut out, false).unwrap(),
+            4
+        );
+        assert_eq!(String::from_utf8(out).unwrap(), "4\n12\n1\n4\n");
+
+        let mut out = Vec::new();
+        write_segment_lengths(gfa.as_bytes(), &mut out, true).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), "12\n4\n4\n1\n");
+    }
+
+    #[test]
+    fn split_writes_each_record_type_to_its_own_file() {
+        let gfa = "H\tVN:Z:1.0\nS\ta\tACGT\nL\ta\t+\tb\t-\t*\nS\tb\tGG\tLN:i:2\n\n\
+                   P\tp\ta+,b-\t*\nW\tsample\t0\tchr1\t0\t6\t>a<b\n";
+        let dir = temp_dir("split");
+        let counts = split_by_record_type(gfa.as_bytes(), &dir).unwrap();
+        assert_eq!(
+            (counts.segments, counts.links, counts.paths, counts.other),
+            (2, 1, 1, 2)
+        );
+
+        let read = |name: &str| std::fs::read_to_string(dir.join(name)).unwrap();
+        assert_eq!(read("segments.gfa"), "S\ta\tACGT\nS\tb\tGG\tLN:i:2\n");
+        assert_eq!(read("links.gfa"), "L\ta\t+\tb\t-\t*\n");
+        assert_eq!(read("paths.gfa"), "P\tp\ta+,b-\t*\n");
+        assert_eq!(
+            read("other.gfa"),
+            "H\tVN:Z:1.0\nW\tsample\t0\tchr1\t0\t6\t>a<b\n"
+        );
+        std::fs::remove_dir_all(&dir).unwrap();
+
+        // CRLF line endings are copied through untouched
+        let gfa = "H\tVN:Z:1.0\r\nS\ta\tACGT\r\n\r\nL\ta\t+\ta\t+\t*\r\nP\tp\ta+\t*";
+        let counts = split_by_record_type(gfa.as_bytes(), &dir).unwrap();
+        assert_eq!(
+            (counts.segments, counts.links, counts.paths, counts.other),
+            (1, 1, 1, 1)
+        );
+        assert_eq!(read("segments.gfa"), "S\ta\tACGT\r\n");
+        assert_eq!(read("links.gfa"), "L\ta\t+\ta\t+\t*\r\n");
+        assert_eq!(read("paths.gfa"), "P\tp\ta+\t*\n");
+        assert_eq!(read("other.gfa"), "H\tVN:Z:1.0\r\n");
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn graph_stats_tsv_parses_back_to_the_same_metrics() {
+        let stats = graph_stats(
+            "S\ta\tACGTACGT\nS\tb\tGG\nS\tc\tT\nL\ta\t+\tb\t+\t*\nL\ta\t+\tc\t+\t*\n\
+             P\tp1\ta+,b+\t*\nP\tp2\ta+,c+\t*\n",
+        );
+        let tsv = stats_to_tsv(&serde_json::to_value(&stats).unwrap());
+
+        let mut lines = tsv.lines();
+        assert_eq!(lines.next(), Some("metric\tvalue"));
+        let rows: HashMap<&str, &str> = lines
+            .map(|line| line.split_once('\t').expect("two columns"))
+            .collect();
+
+        assert_eq!(rows["basic.node_count"], "3");
+        assert_eq!(rows["basic.total_bp"], "11");
+        assert_eq!(
+            rows["basic.mean_node_len"].parse::<f64>().unwrap(),
+            11.0 / 3.0
+        );
+        assert_eq!(rows["n50"], stats.n50.to_string());
+        assert_eq!(rows["degree_histogram.1"], "2");
+        assert_eq!(rows["degree_histogram.2"], "1");
+        assert_eq!(rows["path_step_histogram.2"], "2");
+        assert_eq!(rows["pangenome_classes.core.segments"], "1");
+        // unset optional metrics are left out rather than written as null
+        assert!(!rows.contains_key("ng50"));
+    }
+
+    #[test]
+    fn path_step_histogram_counts_paths_by_length() {
+        let stats = graph_stats(
+            "S\ta\tA\nS\tb\tC\nS\tc\tG\n\
+             P\tp1\ta+,b+\t*\nP\tp2\tb+,c+\t*\nP\tp3\ta+,b+,c+,b-,a-\t*\n",
+        );
+        assert_eq!(stats.path_step_histogram, vec![(2, 2), (5, 1)]);
+    }
+
+    #[test]
+    fn path_step_histogram_is_empty_without_paths() {
+        let stats = graph_stats("S\ta\tA\nS\tb\tC\nL\ta\t+\tb\t+\t*\n");
+        assert!(stats.path_step_histogram.is_empty());
+        assert_eq!(stats.basic.path_count, 0);
+    }
+
+    #[test]
+    fn overlap_adjusted_total_subtracts_link_matches() {
+        let gfa = "S\ta\tACGTACGTAC\nS\tb\tGTACGTACGT\nS\tc\tCCCCCGGGGG\n\
+                   L\ta\t+\tb\t+\t5M\nL\tb\t+\tc\t+\t5M\n";
+        let raw = compute_basic_stats(gfa.as_bytes()).unwrap();
+        let adjusted = compute_basic_stats_overlap_adjusted(gfa.as_bytes()).unwrap();
+
+        assert_eq!(raw.total_bp, 30);
+        assert_eq!(adjusted.basic.total_bp, raw.total_bp);
+        assert_eq!(adjusted.overlap_bp, 10);
+        assert_eq!(adjusted.adjusted_total_bp, 20);
+    }
+
+    #[test]
+    fn overlap_adjusted_total_never_goes_negative() {
+        let gfa = "S\ta\tACG\nL\ta\t+\ta\t+\t5M\n";
+        let adjusted = compute_basic_stats_overlap_adjusted(gfa.as_bytes()).unwrap();
+        assert_eq!((adjusted.overlap_bp, adjusted.adjusted_total_bp), (5, 0));
+    }
+
+    #[test]
+    fn sequence_stats_handle_mixed_case_and_ambiguity_codes() {
+        let mut composition = SequenceStats::default();
+        composition.observe(b"ACgtNnRYKMswbdhv");
+        assert_eq!(
+            composition,
+            SequenceStats {
+                gc: 2,
+                n: 2,
+                soft_masked: 9,
+                total: 16,
+            }
+        );
+
+        // observing in pieces accumulates the same counts
+        let mut split = SequenceStats::default();
+        split.observe(b"ACgtNn");
+        split.observe(b"RYKMswbdhv");
+        assert_eq!(split, composition);
+    }
+
+    #[test]
+    fn every_stats_reader_counts_composition_the_same_way() {
+        let gfa = "S\ta\tACgtNnRY\nS\tb\tggccAAnN\nS\tc\t*\tLN:i:4\n";
+        // (gc, n, soft-masked); the `*` segment contributes nothing
+        let expected = (6, 4, 8);
+        let composition = |s: &BasicStats| (s.gc_bases, s.n_bases, s.soft_masked_bases);
+
+        let basic = compute_basic_stats(gfa.as_bytes()).unwrap();
+        assert_eq!(composition(&basic), expected);
+        assert_eq!(composition(&graph_stats(gfa).basic), expected);
+        let adjusted = compute_basic_stats_overlap_adjusted(gfa.as_bytes()).unwrap();
+        assert_eq!(composition(&adjusted.basic), expected);
+
+        let dir = temp_dir("composition");
+        let input = dir.join("in.gfa");
+        std::fs::write(&input, gfa).unwrap();
+        let progress = compute_basic_stats_from_path_with_progress(&input).unwrap();
+        assert_eq!(composition(&progress), expected);
+        let resumable = compute_basic_stats_resumable(&input, dir.join("ckpt")).unwrap();
+        assert_eq!(composition(&resumable), expected);
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn multi_edges_are_keyed_on_both_ends_and_orientations() {
+        let stats = graph_stats(
+            "S\ta\tA\nS\tb\tC\n\
+             L\ta\t+\tb\t+\t*\nL\ta\t+\tb\t+\t*\nL\ta\t+\tb\t+\t*\n\
+             L\ta\t+\tb\t-\t*\nL\tb\t+\ta\t+\t*\nL\ta\t-\ta\t-\t*\nL\ta\t-\ta\t-\t*\n",
+        );
+        // a+ b+ (three times) and a- a- (twice); the other orientations are distinct
+        assert_eq!(stats.multi_edge_count, 2);
+        assert_eq!(stats.self_loop_count, 2);
+    }
+
+    #[test]
+    fn segments_linked_one_way_but_walked_the_other_are_flagged() {
+        let stats = graph_stats(
+            "S\ts1\tA\nS\ts2\tC\nS\ts3\tG\n\
+             L\ts1\t+\ts2\t+\t*\nL\ts2\t+\ts3\t+\t*\n\
+             P\tp1\ts1+,s2-,s3+\t*\nP\tp2\ts2-\t*\nP\tp3\ts3-,s3+\t*\n",
+        );
+        // s2: links only +, paths only -; s3 is walked both ways; s1 agrees
+        assert_eq!(stats.orientation_mismatches, vec!["s2".to_string()]);
+    }
+
+    #[test]
+    fn overlaps_longer_than_their_segments_are_reported() {
+        let stats = graph_stats(
+            "S\ta\tAAAAAAAAAA\nS\tb\tCCCCCCCCCC\n\
+             L\ta\t+\tb\t-\t50M\nL\ta\t+\tb\t+\t5M\nL\ta\t-\tb\t+\t8M4D\n",
+        );
+        assert_eq!(
+            stats.overlap_violations,
+            vec![
+                OverlapViolation {
+                    from: "a".to_string(),
+                    from_orient: "+".to_string(),
+                    to: "b".to_string(),
+                    to_orient: "-".to_string(),
+                    overlap: "50M".to_string(),
+                    from_overlap_bp: 50,
+                    to_overlap_bp: 50,
+                    from_len: 10,
+                    to_len: 10,
+                },
+                OverlapViolation {
+                    from: "a".to_string(),
+                    from_orient: "-".to_string(),
+                    to: "b".to_string(),
+                    to_orient: "+".to_string(),
+                    overlap: "8M4D".to_string(),
+                    from_overlap_bp: 12,
+                    to_overlap_bp: 8,
+                    from_len: 10,
+                    to_len: 10,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn overlaps_are_checked_when_links_precede_segments() {
+        let stats = graph_stats(
+            "L\ta\t+\tb\t+\t50M\nL\ta\t+\tghost\t+\t50M\n\
+             S\ta\tAAAAAAAAAA\nS\tb\t*\tLN:i:100\n",
+        );
+        // b is long enough but a is not; ghost's length is unknown
+        assert_eq!(stats.overlap_violations.len(), 1);
+        assert_eq!(
+            (
+                stats.overlap_violations[0].from_len,
+                stats.overlap_violations[0].to_len
+            ),
+            (10, 100)
+        );
+
+        let err = compute_graph_stats("L\ta\t+\tb\t+\t5Q\n".as_bytes()).unwrap_err();
+        assert!(matches!(err, GfaError::MalformedField { field: 5, .. }));
+    }
+
+    #[test]
+    fn graph_stats_report_n50_and_n90() {
+        let stats = graph_stats("S\ta\tAAAAAAAAAA\nS\tb\tCCC\nS\tc\tGG\n");
+        assert_eq!((stats.n50, stats.l50), (10, 1));
+        assert_eq!((stats.n90, stats.l90), (2, 3));
+    }
+
+    #[test]
+    fn ln_tag_lengths_beyond_u32_feed_n50() {
+        let stats = graph_stats("S\ta\t*\tLN:i:5000000000\nS\tb\tACGT\n");
+        assert_eq!(stats.basic.total_bp, 5_000_000_004);
+        assert_eq!(stats.basic.max_node_len, 5_000_000_000);
+        assert_eq!(stats.n50, 5_000_000_000);
+        assert_eq!(stats.l50, 1);
+    }
 }
@@ -1,15 +1,20 @@
 use std::path::PathBuf;
+use std::thread;
+use std::time::Duration;
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 use clap::Parser;
-use pgtools::{compute_basic_stats_from_path, compute_basic_stats_from_path_with_progress};
-use serde_json;
+use pgtools::{
+    compute_basic_stats_from_path_bounded, compute_basic_stats_from_path_with_progress_bounded,
+    compute_basic_stats_overlap_adjusted_from_path_bounded, compute_basic_stats_resumable_bounded,
+    file_signature, is_stdin_path, stats_to_tsv, watch_for_changes, BasicStats,
+};
 
 /// Compute basic streaming stats for a GFA or GFA.GZ file.
 #[derive(Debug, Parser)]
 #[command(name = "pgtools-stats-basic", version, about)]
 struct Args {
-    /// Input GFA or GFA.GZ file
+    /// Input GFA or GFA.GZ file, or - for stdin
     #[arg(value_name = "FILE")]
     input: PathBuf,
 
@@ -17,32 +22,117 @@ struct Args {
     #[arg(long)]
     no_progress: bool,
 
+    /// Abort if any line is longer than this many bytes
+    #[arg(long, value_name = "BYTES")]
+    max_line_length: Option<usize>,
+
+    /// Checkpoint file for resumable runs (disables the progress bar)
+    #[arg(long, value_name = "FILE")]
+    checkpoint: Option<PathBuf>,
+
+    /// Also report total bp minus link overlaps (M bases of each L CIGAR)
+    #[arg(long)]
+    overlap_adjusted: bool,
+
+    /// Recompute every N seconds while the file changes (until interrupted)
+    #[arg(
+        long,
+        value_name = "SECS",
+        value_parser = clap::value_parser!(u64).range(1..),
+        conflicts_with_all = ["overlap_adjusted", "checkpoint"]
+    )]
+    watch: Option<u64>,
+
     /// Output JSON instead of pretty text
     #[arg(long)]
     json: bool,
+
+    /// Output metric<TAB>value rows instead of pretty text
+    #[arg(long, conflicts_with = "json")]
+    tsv: bool,
 }
 
 fn main() -> Result<()> {
     let args = Args::parse();
 
-    let stats = if args.no_progress {
-        compute_basic_stats_from_path(&args.input)?
+    if let Some(interval) = args.watch {
+        if is_stdin_path(&args.input) {
+            bail!("--watch needs a file path, not stdin");
+        }
+        return watch(&args, Duration::from_secs(interval));
+    }
+
+    if args.overlap_adjusted {
+        let adjusted = compute_basic_stats_overlap_adjusted_from_path_bounded(
+            &args.input,
+            args.max_line_length,
+        )?;
+        if args.json {
+            println!("{}", serde_json::to_string_pretty(&adjusted)?);
+            return Ok(());
+        }
+        if args.tsv {
+            print!("{}", stats_to_tsv(&serde_json::to_value(&adjusted)?));
+            return Ok(());
+        }
+
+        print_stats(&args, &adjusted.basic)?;
+        println!("Overlap bp (L, M)  : {}", adjusted.overlap_bp);
+        println!("Adjusted total bp  : {}", adjusted.adjusted_total_bp);
+        return Ok(());
+    }
+
+    let stats = if let Some(checkpoint) = &args.checkpoint {
+        compute_basic_stats_resumable_bounded(&args.input, checkpoint, args.max_line_length)?
+    } else if args.no_progress {
+        compute_basic_stats_from_path_bounded(&args.input, args.max_line_length)?
     } else {
-        compute_basic_stats_from_path_with_progress(&args.input)?
+        compute_basic_stats_from_path_with_progress_bounded(&args.input, args.max_line_length)?
     };
 
+    print_stats(&args, &stats)
+}
+
+/// Re-run stats whenever the input's size or mtime changes, polling every
+/// `interval`. A failed run (e.g. a half-written last line) is reported and
+/// retried on the next change. Runs until the process is interrupted.
+fn watch(args: &Args, interval: Duration) -> Result<()> {
+    watch_for_changes(
+        || file_signature(&args.input).map_err(anyhow::Error::from),
+        || {
+            thread::sleep(interval);
+            true
+        },
+        || {
+            let stats = compute_basic_stats_from_path_bounded(&args.input, args.max_line_length)?;
+            print_stats(args, &stats)?;
+            println!();
+            Ok(())
+        },
+        |err| eprintln!("Error: {err:#}\nWaiting for the next change."),
+    )?;
+    Ok(())
+}
+
+fn print_stats(args: &Args, stats: &BasicStats) -> Result<()> {
     if args.json {
-        let json = serde_json::to_string_pretty(&stats)?;
+        let json = serde_json::to_string_pretty(&stats.summary())?;
         println!("{json}");
         return Ok(());
     }
 
+    if args.tsv {
+        print!("{}", stats_to_tsv(&stats.summary_json()));
+        return Ok(());
+    }
+
     println!("Basic stats for {}", args.input.display());
     println!("-----------------------------------------");
     println!("Total lines        : {}", stats.total_lines);
     println!("Nodes (S)          : {}", stats.node_count);
     println!("Edges (L)          : {}", stats.edge_count);
     println!("Paths (P)          : {}", stats.path_count);
+    println!("Containments (C)   : {}", stats.containment_count);
     println!("Other records      : {}", stats.other_records);
     println!("Comment lines (#)  : {}", stats.comment_lines);
     println!();
@@ -53,6 +143,7 @@ fn main() -> Result<()> {
     println!();
     println!("GC bases           : {}", stats.gc_bases);
     println!("N bases            : {}", stats.n_bases);
+    println!("Soft-masked bases  : {}", stats.soft_masked_bases);
 
     Ok(())
 }